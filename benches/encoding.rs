@@ -0,0 +1,53 @@
+//! Benchmarks for base32 encoding, decoding, parsing, and generation.
+//!
+//! The raw codec benchmarks need the `bench-internals` feature. Run with and
+//! without the `simd` feature to compare the AVX2 and scalar paths:
+//!
+//! ```text
+//! cargo bench --bench encoding --features bench-internals
+//! cargo bench --bench encoding --features bench-internals,simd
+//! ```
+
+// `criterion_group!` generates an undocumented public function
+#![allow(missing_docs)]
+
+use std::hint::black_box;
+use std::str::FromStr;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use typeid_suffix::prelude::*;
+
+const SUFFIX: &str = "01h455vb4pex5vsknk084sn02q";
+const UUID: &str = "01890a5d-ac96-774b-bcce-b302099a8057";
+
+#[cfg(feature = "bench-internals")]
+fn bench_codec(c: &mut Criterion) {
+    use typeid_suffix::bench::{decode_base32, decode_base32_scalar, encode_base32, encode_base32_scalar};
+
+    let uuid = Uuid::from_str(UUID).unwrap().into_bytes();
+    let encoded: [u8; 26] = SUFFIX.as_bytes().try_into().unwrap();
+    c.bench_function("encode_base32", |b| b.iter(|| encode_base32(black_box(&uuid))));
+    c.bench_function("encode_base32_scalar", |b| b.iter(|| encode_base32_scalar(black_box(&uuid))));
+    c.bench_function("decode_base32", |b| b.iter(|| decode_base32(black_box(&encoded))));
+    c.bench_function("decode_base32_scalar", |b| b.iter(|| decode_base32_scalar(black_box(&encoded))));
+}
+
+#[cfg(not(feature = "bench-internals"))]
+const fn bench_codec(_: &mut Criterion) {}
+
+fn bench_conversions(c: &mut Criterion) {
+    let uuid = Uuid::from_str(UUID).unwrap();
+    let suffix = TypeIdSuffix::from_str(SUFFIX).unwrap();
+    c.bench_function("from_uuid", |b| b.iter(|| TypeIdSuffix::from(black_box(uuid))));
+    c.bench_function("to_uuid", |b| b.iter(|| black_box(&suffix).to_uuid()));
+    c.bench_function("from_str", |b| b.iter(|| TypeIdSuffix::from_str(black_box(SUFFIX))));
+    c.bench_function("as_str", |b| b.iter(|| -> &str { black_box(&suffix) }));
+}
+
+fn bench_generation(c: &mut Criterion) {
+    c.bench_function("default", |b| b.iter(TypeIdSuffix::default));
+    c.bench_function("new_v4", |b| b.iter(TypeIdSuffix::new::<V4>));
+}
+
+criterion_group!(benches, bench_codec, bench_conversions, bench_generation);
+criterion_main!(benches);
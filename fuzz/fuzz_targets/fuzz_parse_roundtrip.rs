@@ -0,0 +1,25 @@
+// Seed inputs built from the specification's suffix test vectors live in
+// `seeds/fuzz_parse_roundtrip`. Pass them after the working corpus so new
+// inputs are written to the corpus rather than the seeds:
+//
+//     cargo fuzz run fuzz_parse_roundtrip corpus/fuzz_parse_roundtrip seeds/fuzz_parse_roundtrip
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use typeid_suffix::prelude::*;
+use std::str::FromStr;
+
+fuzz_target!(|data: &[u8]| {
+    // Interpret up to 26 bytes as a candidate suffix string
+    let Ok(candidate) = std::str::from_utf8(&data[..data.len().min(26)]) else {
+        return;
+    };
+
+    // Any accepted string must be the canonical encoding of its UUID
+    if let Ok(suffix) = TypeIdSuffix::from_str(candidate) {
+        let reencoded = TypeIdSuffix::from(suffix.to_uuid());
+        assert_eq!(&*reencoded, candidate);
+        assert_eq!(reencoded, suffix);
+    }
+});
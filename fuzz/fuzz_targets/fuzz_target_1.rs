@@ -12,9 +12,9 @@ fuzz_target!(|data: &[u8]| {
 
     // Test ``UUIDv7``
     let uuid = Uuid::from_bytes(data[..16].try_into().unwrap());
-    if let Ok(suffix) = TypeIdSuffix::new(uuid) {
+    if let Ok(suffix) = TypeIdSuffix::from_uuid(uuid) {
         // Test encoding
-        assert_eq!(suffix.as_str().len(), 26);
+        assert_eq!(suffix.len(), 26);
 
         // Test decoding
         let decoded: Result<Uuid, _> = suffix.clone().try_into();
@@ -22,18 +22,18 @@ fuzz_target!(|data: &[u8]| {
         assert_eq!(decoded.unwrap(), uuid);
 
         // Test FromStr
-        let from_str = TypeIdSuffix::from_str(suffix.as_str());
+        let from_str = TypeIdSuffix::from_str(&suffix);
         assert!(from_str.is_ok());
-        assert_eq!(from_str.unwrap().as_str(), suffix.as_str());
+        assert_eq!(from_str.unwrap(), suffix);
     }
 
     // Test other UUID versions
-    let suffix = TypeIdSuffix::new(uuid);
+    let suffix = TypeIdSuffix::from_uuid(uuid);
     if suffix.is_ok() {
         let suffix = suffix.unwrap();
 
         // Test encoding
-        assert_eq!(suffix.as_str().len(), 26);
+        assert_eq!(suffix.len(), 26);
 
         // Test decoding
         let decoded: Result<Uuid, _> = suffix.clone().try_into();
@@ -41,9 +41,9 @@ fuzz_target!(|data: &[u8]| {
         assert_eq!(decoded.unwrap(), uuid);
 
         // Test FromStr
-        let from_str = TypeIdSuffix::from_str(suffix.as_str());
+        let from_str = TypeIdSuffix::from_str(&suffix);
         assert!(from_str.is_ok());
-        assert_eq!(from_str.unwrap().as_str(), suffix.as_str());
+        assert_eq!(from_str.unwrap(), suffix);
     }
 
     // Test invalid inputs
@@ -0,0 +1,35 @@
+//! `arrayvec` support for ``TypeIdSuffix``.
+//!
+//! `to_string` heap-allocates a 26-byte `String`. On hot paths (such as
+//! logging) an `ArrayString<26>` holds the same text inline on the stack.
+
+use ::arrayvec::ArrayString;
+
+use crate::typeid_suffix::TypeIdSuffix;
+
+impl TypeIdSuffix {
+    /// Renders the ``TypeIdSuffix`` into a stack-allocated `ArrayString`.
+    ///
+    /// The suffix is always exactly 26 characters, so this never allocates.
+    ///
+    /// # Returns
+    ///
+    /// An `ArrayString<26>` containing the suffix string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// let inline = suffix.to_array_string();
+    /// assert_eq!(inline.as_str(), suffix.as_ref());
+    /// assert!(inline.is_full());
+    /// ```
+    #[must_use]
+    pub fn to_array_string(&self) -> ArrayString<26> {
+        let mut inline = ArrayString::new();
+        inline.push_str(self);
+        inline
+    }
+}
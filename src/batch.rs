@@ -0,0 +1,285 @@
+//! Batch conversions between UUIDs and ``TypeIdSuffix`` values.
+//!
+//! These helpers remove the boilerplate loops from bulk migrations, where a
+//! whole column of UUIDs needs converting to suffixes (or back) at once.
+
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+use crate::errors::DecodeError;
+use crate::typeid_suffix::TypeIdSuffix;
+
+/// Encodes a slice of UUIDs into ``TypeIdSuffix`` values.
+///
+/// Each UUID is encoded independently, so the output has the same length and
+/// order as the input.
+///
+/// # Arguments
+///
+/// * `uuids`: The UUIDs to encode.
+///
+/// # Returns
+///
+/// A `Vec` containing one ``TypeIdSuffix`` per input UUID.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::batch::encode_many;
+/// use typeid_suffix::prelude::*;
+///
+/// let uuids = [Uuid::now_v7(), Uuid::new_v4()];
+/// let suffixes = encode_many(&uuids);
+/// assert_eq!(suffixes.len(), 2);
+/// assert_eq!(suffixes[0].to_uuid(), uuids[0]);
+/// ```
+#[must_use]
+pub fn encode_many(uuids: &[Uuid]) -> Vec<TypeIdSuffix> {
+    uuids.iter().copied().map(TypeIdSuffix::from).collect()
+}
+
+/// Decodes a slice of ``TypeIdSuffix`` values back into UUIDs.
+///
+/// Every ``TypeIdSuffix`` is validated on construction, so decoding cannot fail.
+///
+/// # Arguments
+///
+/// * `suffixes`: The suffixes to decode.
+///
+/// # Returns
+///
+/// A `Vec` containing one UUID per input ``TypeIdSuffix``.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::batch::{decode_many, encode_many};
+/// use typeid_suffix::prelude::*;
+///
+/// let uuids = vec![Uuid::now_v7(), Uuid::new_v4()];
+/// assert_eq!(decode_many(&encode_many(&uuids)), uuids);
+/// ```
+#[must_use]
+pub fn decode_many(suffixes: &[TypeIdSuffix]) -> Vec<Uuid> {
+    suffixes.iter().map(TypeIdSuffix::to_uuid).collect()
+}
+
+/// Encodes UUIDs into ``TypeIdSuffix`` values, dropping duplicates.
+///
+/// The first occurrence of each UUID is kept, so the output preserves the
+/// order in which distinct UUIDs were first seen.
+///
+/// # Arguments
+///
+/// * `uuids`: The UUIDs to encode.
+///
+/// # Returns
+///
+/// A `Vec` containing one ``TypeIdSuffix`` per distinct input UUID.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::batch::from_uuids_dedup;
+/// use typeid_suffix::prelude::*;
+///
+/// let (a, b) = (Uuid::now_v7(), Uuid::new_v4());
+/// let suffixes = from_uuids_dedup([a, b, a]);
+/// assert_eq!(suffixes, [TypeIdSuffix::from(a), TypeIdSuffix::from(b)]);
+/// ```
+#[must_use]
+pub fn from_uuids_dedup<I: IntoIterator<Item = Uuid>>(uuids: I) -> Vec<TypeIdSuffix> {
+    let mut seen = HashSet::new();
+    uuids
+        .into_iter()
+        .map(TypeIdSuffix::from)
+        .filter(|suffix| seen.insert(suffix.to_array()))
+        .collect()
+}
+
+/// Groups ``TypeIdSuffix`` values by the version number of their decoded UUID.
+///
+/// Each suffix is decoded once. Groups are keyed by the raw version number from
+/// `Uuid::get_version_num` (for example `4` or `7`), because `uuid::Version`
+/// does not implement `Hash`; unrecognized versions get a group like any other.
+/// Within a group, suffixes keep their input order.
+///
+/// # Arguments
+///
+/// * `suffixes`: The suffixes to group.
+///
+/// # Returns
+///
+/// A `HashMap` from UUID version number to the suffixes with that version.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::batch::partition_by_version;
+/// use typeid_suffix::prelude::*;
+///
+/// let suffixes = [TypeIdSuffix::new::<V4>(), TypeIdSuffix::new::<V7>(), TypeIdSuffix::new::<V4>()];
+/// let groups = partition_by_version(&suffixes);
+/// assert_eq!(groups[&4].len(), 2);
+/// assert_eq!(groups[&7].len(), 1);
+/// ```
+#[must_use]
+pub fn partition_by_version(suffixes: &[TypeIdSuffix]) -> HashMap<usize, Vec<TypeIdSuffix>> {
+    let mut groups: HashMap<usize, Vec<TypeIdSuffix>> = HashMap::new();
+    for suffix in suffixes {
+        groups.entry(suffix.to_uuid().get_version_num()).or_default().push(suffix.clone());
+    }
+    groups
+}
+
+/// Validates each string as a ``TypeIdSuffix``, reporting every failure.
+///
+/// Unlike collecting into a `Result<Vec<_>, _>`, this does not stop at the first
+/// invalid input, so callers can report every bad row of an import at once.
+/// Each input is validated exactly as `from_str` would, and the parsed value is
+/// discarded.
+///
+/// # Arguments
+///
+/// * `inputs`: The candidate suffix strings to validate.
+///
+/// # Returns
+///
+/// A `Vec` with one result per input, in the same order: `Ok(())` for a valid
+/// suffix, or the `DecodeError` that `from_str` would have returned.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::batch::validate_many;
+/// use typeid_suffix::prelude::*;
+///
+/// let results = validate_many(&["01h455vb4pex5vsknk084sn02q", "too_short"]);
+/// assert_eq!(results[0], Ok(()));
+/// assert_eq!(results[1], Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength)));
+/// ```
+#[must_use]
+pub fn validate_many(inputs: &[&str]) -> Vec<Result<(), DecodeError>> {
+    inputs.iter().map(|input| TypeIdSuffix::from_str(input).map(|_| ())).collect()
+}
+
+/// Returns the greatest ``TypeIdSuffix`` in `suffixes`.
+///
+/// Ordering follows the `Ord` impl, so for `UUIDv7` suffixes this is the most
+/// recently generated one.
+///
+/// # Arguments
+///
+/// * `suffixes`: The suffixes to search.
+///
+/// # Returns
+///
+/// The greatest suffix, or `None` if `suffixes` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::batch::{generate_batch, max_of};
+///
+/// let batch = generate_batch(3);
+/// assert_eq!(max_of(&batch), batch.last());
+/// assert_eq!(max_of(&[]), None);
+/// ```
+#[must_use]
+pub fn max_of<'a, I: IntoIterator<Item = &'a TypeIdSuffix>>(suffixes: I) -> Option<&'a TypeIdSuffix> {
+    suffixes.into_iter().max()
+}
+
+/// Returns the least ``TypeIdSuffix`` in `suffixes`.
+///
+/// Ordering follows the `Ord` impl, so for `UUIDv7` suffixes this is the
+/// earliest generated one.
+///
+/// # Arguments
+///
+/// * `suffixes`: The suffixes to search.
+///
+/// # Returns
+///
+/// The least suffix, or `None` if `suffixes` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::batch::{generate_batch, min_of};
+///
+/// let batch = generate_batch(3);
+/// assert_eq!(min_of(&batch), batch.first());
+/// assert_eq!(min_of(&[]), None);
+/// ```
+#[must_use]
+pub fn min_of<'a, I: IntoIterator<Item = &'a TypeIdSuffix>>(suffixes: I) -> Option<&'a TypeIdSuffix> {
+    suffixes.into_iter().min()
+}
+
+/// Generates `n` `UUIDv7` ``TypeIdSuffix`` values in strictly increasing order.
+///
+/// The first suffix comes from the current time. Each following one reuses its
+/// millisecond and treats the 74 random bits as a counter, incrementing it by
+/// one; if the counter overflows, the timestamp moves forward a millisecond. The
+/// whole batch therefore sorts in generation order, which suits bulk inserts
+/// into time-ordered indexes.
+///
+/// The only exception is the largest `UUIDv7`, at the end of the 48-bit
+/// millisecond range in the year 10889. Once the batch reaches it, the remaining
+/// suffixes repeat it rather than wrapping around to the Unix epoch.
+///
+/// Generation reads the system clock, so this needs the standard library. The
+/// crate has no `std` feature to gate it behind, because every build already
+/// depends on `std`.
+///
+/// # Arguments
+///
+/// * `n`: The number of suffixes to generate.
+///
+/// # Returns
+///
+/// A `Vec` of `n` suffixes, each strictly greater than the one before it unless
+/// the largest `UUIDv7` has been reached.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::batch::generate_batch;
+///
+/// let batch = generate_batch(100);
+/// assert_eq!(batch.len(), 100);
+/// assert!(batch.windows(2).all(|pair| pair[0] < pair[1]));
+/// ```
+#[must_use]
+pub fn generate_batch(n: usize) -> Vec<TypeIdSuffix> {
+    std::iter::successors(Some(Uuid::now_v7()), |uuid| Some(next_v7(uuid)))
+        .take(n)
+        .map(TypeIdSuffix::from)
+        .collect()
+}
+
+// Returns the smallest `UUIDv7` greater than `uuid`, or `uuid` itself if it is the
+// largest. The 12-bit `rand_a` and 62-bit `rand_b` fields form a 74-bit counter;
+// the version and variant bits between and around them are left in place.
+pub(crate) const fn next_v7(uuid: &Uuid) -> Uuid {
+    const RAND_B_MASK: u128 = (1 << 62) - 1;
+    const COUNTER_LIMIT: u128 = 1 << 74;
+    const MILLIS_LIMIT: u128 = 1 << 48;
+
+    let value = uuid.as_u128();
+    let millis = value >> 80;
+    let counter = (((value >> 64) & 0xFFF) << 62) | (value & RAND_B_MASK);
+    let (millis, counter) = if counter + 1 < COUNTER_LIMIT {
+        (millis, counter + 1)
+    } else if millis + 1 < MILLIS_LIMIT {
+        (millis + 1, 0)
+    } else {
+        // Moving past the last millisecond would wrap the timestamp to zero
+        return *uuid;
+    };
+
+    Uuid::from_u128((millis << 80) | (0x7 << 76) | ((counter >> 62) << 64) | (0b10 << 62) | (counter & RAND_B_MASK))
+}
@@ -0,0 +1,39 @@
+//! Internal encoding functions exposed for benchmarks.
+//!
+//! This module is only compiled with the `bench-internals` feature and is not
+//! part of the stable API; it exists so that `benches/encoding.rs` can measure
+//! the base32 codec on its own, separately from parsing and validation.
+
+use crate::encoding;
+use crate::errors::DecodeError;
+
+/// Encodes 16 bytes into 26 base32 characters, using the `simd` path when available.
+#[must_use]
+pub fn encode_base32(uuid: &[u8; 16]) -> [u8; 26] {
+    encoding::encode_base32(uuid)
+}
+
+/// Decodes 26 base32 characters into 16 bytes, using the `simd` path when available.
+///
+/// # Errors
+///
+/// Returns a `DecodeError` if the input contains a character outside the
+/// alphabet or its first character is greater than `'7'`.
+pub fn decode_base32(encoded: &[u8; 26]) -> Result<[u8; 16], DecodeError> {
+    encoding::decode_base32(encoded)
+}
+
+/// Encodes 16 bytes into 26 base32 characters, always using the scalar loop.
+#[must_use]
+pub const fn encode_base32_scalar(uuid: &[u8; 16]) -> [u8; 26] {
+    encoding::encode::<16, 26>(uuid)
+}
+
+/// Decodes 26 base32 characters into 16 bytes, always using the scalar loop.
+///
+/// # Errors
+///
+/// Returns the same errors as `decode_base32`.
+pub const fn decode_base32_scalar(encoded: &[u8; 26]) -> Result<[u8; 16], DecodeError> {
+    encoding::decode::<16, 26>(encoded)
+}
@@ -0,0 +1,30 @@
+//! `borsh` support for ``TypeIdSuffix``.
+//!
+//! ``TypeIdSuffix`` is serialized as the 16 bytes of its decoded UUID rather than
+//! the 26-character string, which keeps Borsh messages compact.
+
+use std::io::{Error, ErrorKind, Read, Result, Write};
+
+use ::borsh::{BorshDeserialize, BorshSerialize};
+use uuid::Uuid;
+
+use crate::typeid_suffix::TypeIdSuffix;
+
+impl BorshSerialize for TypeIdSuffix {
+    /// Serializes the ``TypeIdSuffix`` as the 16 bytes of its decoded UUID.
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(self.to_uuid().as_bytes())
+    }
+}
+
+impl BorshDeserialize for TypeIdSuffix {
+    /// Deserializes a ``TypeIdSuffix`` from the 16 bytes of a UUID.
+    ///
+    /// The UUID is validated with `from_uuid`, so this fails if the reader does
+    /// not contain enough bytes or, with the `strict-global` feature, if the
+    /// UUID is rejected. A rejected UUID is reported as `ErrorKind::InvalidData`.
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let bytes = <[u8; 16]>::deserialize_reader(reader)?;
+        Self::from_uuid(Uuid::from_bytes(bytes)).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+}
@@ -0,0 +1,87 @@
+//! A configurable parser for ``TypeIdSuffix``.
+//!
+//! `from_str` follows the `TypeID` specification and accepts any suffix that
+//! decodes to a 128-bit value. `TypeIdSuffixBuilder` lets a caller opt in to
+//! stricter UUID validation for a single parse site, without the crate-wide
+//! effect of the `strict-global` feature.
+
+use std::str::FromStr;
+
+use crate::errors::DecodeError;
+use crate::typeid_suffix::TypeIdSuffix;
+
+/// A builder that configures how ``TypeIdSuffix`` strings are parsed.
+///
+/// The default configuration is permissive and behaves exactly like `from_str`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TypeIdSuffixBuilder {
+    strict: bool,
+}
+
+impl TypeIdSuffixBuilder {
+    /// Creates a new builder with the default, permissive configuration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let parser = TypeIdSuffixBuilder::new();
+    /// assert!(parser.parse("01h455vb4pex5vsknk084sn02q").is_ok());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { strict: false }
+    }
+
+    /// Sets whether parsing requires a standard UUID.
+    ///
+    /// In strict mode the decoded UUID must have the RFC4122 variant *and* a
+    /// version from 1 to 8. In permissive mode (the default) either condition
+    /// is enough, as `from_str` allows.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict`: `true` to enable strict validation.
+    ///
+    /// # Returns
+    ///
+    /// The updated builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let parser = TypeIdSuffixBuilder::new().strict(true);
+    /// assert!(parser.parse("00000000000000000000000000").is_err());
+    /// ```
+    #[must_use]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Parses a string slice into a ``TypeIdSuffix`` using this configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: The string slice to parse.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the parsed ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_str`. In strict mode, also returns
+    /// `InvalidUuidReason::InvalidVariant` or `InvalidUuidReason::InvalidVersion`
+    /// if the decoded UUID is not a standard RFC4122 UUID.
+    pub fn parse(&self, input: &str) -> Result<TypeIdSuffix, DecodeError> {
+        let suffix = TypeIdSuffix::from_str(input)?;
+        if self.strict {
+            TypeIdSuffix::check_strict_uuid(&suffix.to_uuid()).map_err(DecodeError::InvalidUuid)?;
+        }
+        Ok(suffix)
+    }
+}
@@ -0,0 +1,62 @@
+//! `clap` support for ``TypeIdSuffix``.
+//!
+//! `TypeIdSuffixValueParser` validates command-line arguments with `from_str`
+//! at parse time, so a malformed suffix is reported by `clap` alongside the
+//! argument it was given for. ``TypeIdSuffix`` also implements
+//! `ValueParserFactory`, so `value_parser!(TypeIdSuffix)` and `clap`'s derive
+//! macros select this parser automatically.
+
+use std::ffi::OsStr;
+use std::str::FromStr;
+
+use ::clap::builder::{TypedValueParser, ValueParserFactory};
+use ::clap::error::{Error, ErrorKind};
+use ::clap::{Arg, Command};
+
+use crate::typeid_suffix::TypeIdSuffix;
+
+/// A `clap` value parser that accepts ``TypeIdSuffix`` arguments.
+///
+/// Invalid values are rejected with a `ValueValidation` error naming the
+/// argument, the offending value, and the `DecodeError` reason.
+///
+/// # Examples
+///
+/// ```
+/// use clap::{Arg, Command};
+/// use typeid_suffix::prelude::*;
+///
+/// let command = Command::new("app").arg(Arg::new("id").long("id").value_parser(TypeIdSuffixValueParser));
+///
+/// let matches = command.clone().try_get_matches_from(["app", "--id", "01h455vb4pex5vsknk084sn02q"]).unwrap();
+/// assert_eq!(matches.get_one::<TypeIdSuffix>("id").unwrap().to_string(), "01h455vb4pex5vsknk084sn02q");
+///
+/// let error = command.try_get_matches_from(["app", "--id", "too_short"]).unwrap_err();
+/// assert_eq!(error.kind(), clap::error::ErrorKind::ValueValidation);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TypeIdSuffixValueParser;
+
+impl TypedValueParser for TypeIdSuffixValueParser {
+    type Value = TypeIdSuffix;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<Self::Value, Error> {
+        let arg = arg.map_or_else(|| "...".to_string(), ToString::to_string);
+        let Some(input) = value.to_str() else {
+            return Err(Error::raw(ErrorKind::InvalidUtf8, format!("invalid UTF-8 in value for '{arg}'\n"))
+                .with_cmd(cmd));
+        };
+        TypeIdSuffix::from_str(input).map_err(|error| {
+            Error::raw(ErrorKind::ValueValidation, format!("invalid value '{input}' for '{arg}': {error}\n"))
+                .with_cmd(cmd)
+        })
+    }
+}
+
+impl ValueParserFactory for TypeIdSuffix {
+    type Parser = TypeIdSuffixValueParser;
+
+    fn value_parser() -> Self::Parser {
+        TypeIdSuffixValueParser
+    }
+}
@@ -0,0 +1,122 @@
+//! Conformance checking against the `TypeID` reference implementation.
+//!
+//! The reference repository publishes its test vectors as a JSON array of
+//! `{name, typeid, prefix, uuid}` objects. This module parses that format and
+//! checks every entry against this crate, so the crate can be used as a
+//! drop-in conformance checker.
+//!
+//! This crate implements only the suffix, so each entry's prefix is validated
+//! with `validate_prefix` (via `from_typeid_str`) and otherwise discarded.
+
+use std::fmt;
+
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::errors::{DecodeError, PrefixError};
+use crate::typeid_suffix::TypeIdSuffix;
+
+/// Represents errors that can occur while checking reference test vectors.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ConformanceError {
+    /// The input is not a valid reference JSON document.
+    Json(serde_json::Error),
+    /// The named entry's `typeid` could not be parsed.
+    Decode {
+        /// The `name` of the failing entry.
+        name: String,
+        /// The underlying decode error.
+        error: DecodeError,
+    },
+    /// The named entry's `typeid` has an invalid prefix.
+    InvalidPrefix {
+        /// The `name` of the failing entry.
+        name: String,
+        /// The underlying prefix error.
+        error: PrefixError,
+    },
+    /// The named entry's `typeid` does not decode to its `uuid`.
+    UuidMismatch {
+        /// The `name` of the failing entry.
+        name: String,
+    },
+}
+
+impl fmt::Display for ConformanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(error) => write!(f, "Invalid reference JSON: {error}"),
+            Self::Decode { name, error } => write!(f, "Test vector `{name}` failed to decode: {error}"),
+            Self::InvalidPrefix { name, error } => write!(f, "Test vector `{name}` has an invalid prefix: {error}"),
+            Self::UuidMismatch { name } => write!(f, "Test vector `{name}` does not match its UUID"),
+        }
+    }
+}
+
+impl std::error::Error for ConformanceError {}
+
+#[derive(Deserialize)]
+struct ReferenceEntry {
+    name: String,
+    typeid: String,
+    uuid: Uuid,
+}
+
+/// Parses and checks the reference implementation's `valid.json` test vectors.
+///
+/// Every entry's `typeid` is parsed with `from_typeid_str`, its prefix must pass
+/// `validate_prefix`, and the decoded suffix must match the entry's `uuid` exactly.
+///
+/// # Arguments
+///
+/// * `json`: The contents of a reference `valid.json` file.
+///
+/// # Returns
+///
+/// A `Result` containing each entry's `name` paired with its parsed ``TypeIdSuffix``,
+/// in document order, or a `ConformanceError`.
+///
+/// # Errors
+///
+/// Returns `ConformanceError::Json` if the input is not a valid reference document,
+/// `ConformanceError::InvalidPrefix` if an entry's prefix is invalid,
+/// `ConformanceError::Decode` if its suffix fails to parse, and
+/// `ConformanceError::UuidMismatch` if it decodes to a different UUID.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::conformance::from_reference_json;
+///
+/// let json = r#"[{
+///     "name": "valid-uuidv7",
+///     "typeid": "prefix_01h455vb4pex5vsknk084sn02q",
+///     "prefix": "prefix",
+///     "uuid": "01890a5d-ac96-774b-bcce-b302099a8057"
+/// }]"#;
+///
+/// let vectors = from_reference_json(json).unwrap();
+/// assert_eq!(vectors[0].0, "valid-uuidv7");
+/// assert_eq!(vectors[0].1.to_string(), "01h455vb4pex5vsknk084sn02q");
+/// ```
+pub fn from_reference_json(json: &str) -> Result<Vec<(String, TypeIdSuffix)>, ConformanceError> {
+    let entries: Vec<ReferenceEntry> = serde_json::from_str(json).map_err(ConformanceError::Json)?;
+
+    entries
+        .into_iter()
+        .map(|entry| {
+            let suffix = match TypeIdSuffix::from_typeid_str(&entry.typeid) {
+                Ok((_, suffix)) => suffix,
+                Err(DecodeError::InvalidPrefix(error)) => {
+                    return Err(ConformanceError::InvalidPrefix { name: entry.name, error })
+                }
+                Err(error) => return Err(ConformanceError::Decode { name: entry.name, error }),
+            };
+            if suffix.to_uuid() != entry.uuid {
+                return Err(ConformanceError::UuidMismatch { name: entry.name });
+            }
+            Ok((entry.name, suffix))
+        })
+        .collect()
+}
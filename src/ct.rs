@@ -0,0 +1,49 @@
+//! Constant-time comparison for ``TypeIdSuffix`` using `subtle`.
+//!
+//! `PartialEq` stops at the first differing byte, so the time it takes reveals
+//! how long a matching prefix the two suffixes share. That does not matter for
+//! ordinary identifiers, but when a suffix doubles as a secret (a bearer token,
+//! an unguessable share link, or a capability reference) an attacker who can
+//! time comparisons may recover it one character at a time. Compare such
+//! suffixes with `ct_eq` instead.
+
+use ::subtle::{Choice, ConstantTimeEq};
+
+use crate::typeid_suffix::TypeIdSuffix;
+
+impl TypeIdSuffix {
+    /// Compares two ``TypeIdSuffix`` values in constant time.
+    ///
+    /// All 26 bytes are always compared, so the running time does not depend
+    /// on where the suffixes differ. Use this instead of `==` when the suffix
+    /// is a secret.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The ``TypeIdSuffix`` to compare against.
+    ///
+    /// # Returns
+    ///
+    /// A `subtle::Choice` that is `1` if the suffixes are equal and `0` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let token = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// let presented = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert!(bool::from(token.ct_eq(&presented)));
+    /// ```
+    #[must_use]
+    pub fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConstantTimeEq for TypeIdSuffix {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        Self::ct_eq(self, other)
+    }
+}
@@ -0,0 +1,44 @@
+//! `diesel` support for ``TypeIdSuffix``.
+//!
+//! ``TypeIdSuffix`` maps onto the `Text` SQL type on any backend whose text
+//! type is backed by strings. Together with the `AsExpression` and `FromSqlRow`
+//! derives on the type itself, this lets a suffix be used in `Queryable` and
+//! `Insertable` structs and in query builder expressions. Values read from the
+//! database are validated with `from_str`.
+
+use std::str::FromStr;
+
+use ::diesel::backend::Backend;
+use ::diesel::deserialize::{self, FromSql};
+use ::diesel::serialize::{self, Output, ToSql};
+use ::diesel::sql_types::Text;
+
+use crate::typeid_suffix::TypeIdSuffix;
+
+impl<DB> ToSql<Text, DB> for TypeIdSuffix
+where
+    DB: Backend,
+    str: ToSql<Text, DB>,
+{
+    /// Writes the ``TypeIdSuffix`` as its 26-character string representation.
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        <str as ToSql<Text, DB>>::to_sql(self, out)
+    }
+}
+
+impl<DB> FromSql<Text, DB> for TypeIdSuffix
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    /// Reads a ``TypeIdSuffix`` from a text value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `DecodeError` produced by `from_str` if the stored value
+    /// is not a valid ``TypeIdSuffix``.
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let s = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+        Ok(Self::from_str(&s)?)
+    }
+}
@@ -1,61 +1,153 @@
+//! The base32 alphabet used by `TypeID` suffixes.
+//!
+//! These tables are the single source of truth for the encoding, exposed so
+//! that tooling (editor highlighting, external validators) can share them
+//! instead of hardcoding the alphabet.
+//!
+//! ```
+//! use typeid_suffix::encoding::{ALPHABET, DECODE_TABLE};
+//!
+//! assert_eq!(ALPHABET.len(), 32);
+//! assert_eq!(DECODE_TABLE[b'i' as usize], 0xFF);
+//! assert_eq!(DECODE_TABLE[b'z' as usize], 31);
+//! ```
+
 #![allow(clippy::cast_possible_truncation)]
 
 use crate::errors::{DecodeError, InvalidSuffixReason};
 
-// The base32 encoding alphabet as specified in the `TypeId`specification.
-// This is the same as Crockford's base32 encoding, but `TypeId`uses it in a strict manner:
-// always lowercase, no hyphens, and no decoding of multiple ambiguous characters to the same value.
-const ENCODE_TABLE: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+/// The base32 encoding alphabet as specified in the `TypeID` specification.
+///
+/// This is the same as Crockford's base32 encoding, but `TypeID` uses it in a strict manner:
+/// always lowercase, no hyphens, and no decoding of multiple ambiguous characters to the same value.
+/// The letters `i`, `l`, `o`, and `u` are excluded.
+pub const ALPHABET: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
 
-// A lookup table for decoding base32 characters back to their 5-bit values.
-// This is the inverse of ENCODE_TABLE, where the index is the ASCII value of the character,
-// and the value is the 5-bit integer it represents. 0xFF is used for invalid characters.
+/// A lookup table for decoding base32 characters back to their 5-bit values.
+///
+/// This is the inverse of `ALPHABET`: the index is the ASCII value of the character,
+/// and the value is the 5-bit integer it represents. `0xFF` marks invalid characters.
 pub const DECODE_TABLE: [u8; 256] = {
     let mut table = [0xFF; 256];
     let mut i = 0;
     while i < 32 {
-        table[ENCODE_TABLE[i] as usize] = i as u8;
+        table[ALPHABET[i] as usize] = i as u8;
         i += 1;
     }
     table
 };
 
 // Encodes a 16-byte UUID into a 26-character base32 string as per the `TypeId`specification.
-pub fn encode_base32(uuid: &[u8; 16]) -> [u8; 26] {
-    // Convert the 16-byte UUID to a 128-bit integer in big-endian order
-    let mut uuid_int = u128::from_be_bytes(*uuid);
-    let mut encoded_output = [0u8; 26];
+// With the `simd` feature, this uses AVX2 when the CPU supports it. Use `encode::<16, 26>`
+// directly in `const` contexts.
+#[allow(clippy::missing_const_for_fn)] // Runtime feature detection cannot be `const`
+pub(crate) fn encode_base32(uuid: &[u8; 16]) -> [u8; 26] {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if std::arch::is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 support was detected above
+        #[allow(unsafe_code)]
+        return unsafe { crate::simd::encode_base32(uuid) };
+    }
+    encode::<16, 26>(uuid)
+}
+
+// Decodes a 26-character base32 string back into a 16-byte UUID as per the `TypeId`specification.
+// With the `simd` feature, this uses AVX2 when the CPU supports it. Use `decode::<16, 26>`
+// directly in `const` contexts.
+#[allow(clippy::missing_const_for_fn)] // Runtime feature detection cannot be `const`
+pub(crate) fn decode_base32(encoded: &[u8; 26]) -> Result<[u8; 16], DecodeError> {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if std::arch::is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 support was detected above
+        #[allow(unsafe_code)]
+        return unsafe { crate::simd::decode_base32(encoded) };
+    }
+    decode::<16, 26>(encoded)
+}
+
+// Decodes a 26-character base32 string that has already been validated, such as
+// the bytes of a `TypeIdSuffix`. This never fails or panics: characters outside the
+// alphabet decode as zero and bits above the 128th are discarded, neither of which
+// can happen for validated input.
+pub(crate) fn decode_base32_validated(encoded: &[u8; 26]) -> [u8; 16] {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if std::arch::is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 support was detected above
+        #[allow(unsafe_code)]
+        if let Ok(decoded) = unsafe { crate::simd::decode_base32(encoded) } {
+            return decoded;
+        }
+    }
+    encoded
+        .iter()
+        .fold(0u128, |value, &byte| (value << 5) | u128::from(DECODE_TABLE[byte as usize] & 0x1F))
+        .to_be_bytes()
+}
+
+// Encodes `N` bytes into `M` base32 characters, where `M` is the smallest number of
+// 5-bit characters that can hold `N * 8` bits. The value is treated as a big-endian
+// integer, so the leading `M * 5 - N * 8` bits of the output are always zero.
+pub(crate) const fn encode<const N: usize, const M: usize>(bytes: &[u8; N]) -> [u8; M] {
+    const { assert!(N <= 16 && M == (N * 8).div_ceil(5), "M must be ceil(N * 8 / 5)") };
 
-    // Encode each 5-bit chunk of the 128-bit integer into a base32 character,
+    // Convert the input bytes to an integer in big-endian order
+    // (`while` loops rather than iterators so that this function can be `const`)
+    let mut value = 0u128;
+    let mut i = 0;
+    while i < N {
+        value = (value << 8) | bytes[i] as u128;
+        i += 1;
+    }
+    let mut encoded_output = [0u8; M];
+
+    // Encode each 5-bit chunk of the integer into a base32 character,
     // iterating in reverse because we're processing from least significant to most significant bits
-    for index in (0..26).rev() {
-        // Extract the least significant 5 bits and use them as an index into the ENCODE_TABLE
-        encoded_output[index] = ENCODE_TABLE[(uuid_int & 0x1F) as usize];
+    let mut index = M;
+    while index > 0 {
+        index -= 1;
+        // Extract the least significant 5 bits and use them as an index into the ALPHABET
+        encoded_output[index] = ALPHABET[(value & 0x1F) as usize];
         // Shift right by 5 bits to process the next chunk
-        uuid_int >>= 5;
+        value >>= 5;
     }
 
-    // The resulting output is a 26-character base32 encoded string
+    // The resulting output is an `M`-character base32 encoded string
     encoded_output
 }
 
-// Decodes a 26-character base32 string back into a 16-byte UUID as per the `TypeId`specification.
-pub fn decode_base32(encoded: &[u8; 26]) -> Result<[u8; 16], DecodeError> {
-    let mut uuid_int = 0u128;
+// Decodes `M` base32 characters back into `N` bytes, the inverse of `encode`.
+pub(crate) const fn decode<const N: usize, const M: usize>(encoded: &[u8; M]) -> Result<[u8; N], DecodeError> {
+    const { assert!(N <= 16 && M == (N * 8).div_ceil(5), "M must be ceil(N * 8 / 5)") };
+
+    let mut value = 0u128;
 
     // Iterate over each character in the encoded input
-    for &character in encoded {
+    // (a `while` loop rather than `for` so that this function can be `const`)
+    let mut i = 0;
+    while i < M {
         // Look up the 5-bit value corresponding to this character
-        let value = DECODE_TABLE[character as usize];
+        let digit = DECODE_TABLE[encoded[i] as usize];
         // If the character is invalid (not part of the base32 alphabet), return an error
-        if value == 0xFF {
+        if digit == 0xFF {
             return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidCharacter));
         }
+        // `M` characters carry more than `N * 8` bits, so the excess top bits must be zero.
+        // If they are set, the value would not fit in `N` bytes, so reject the input instead
+        if value >> (N * 8 - 5) != 0 {
+            return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter));
+        }
         // Shift the existing number left by 5 bits and add the new 5-bit value
-        uuid_int = (uuid_int << 5) | u128::from(value);
+        value = (value << 5) | digit as u128;
+        i += 1;
     }
 
-    // Convert the resulting 128-bit integer back to a 16-byte array in big-endian order
-    Ok(uuid_int.to_be_bytes())
+    // Convert the resulting integer back to an `N`-byte array in big-endian order
+    let mut decoded_output = [0u8; N];
+    let mut j = N;
+    while j > 0 {
+        j -= 1;
+        decoded_output[j] = value as u8;
+        value >>= 8;
+    }
+    Ok(decoded_output)
 }
-
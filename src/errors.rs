@@ -3,97 +3,180 @@
 //! This module defines the error types used throughout the `TypeID` suffix
 //! implementation, providing detailed information about various failure modes
 //! during encoding, decoding, and validation processes.
+//!
+//! Every error enum is `#[non_exhaustive]`, so new failure reasons can be added
+//! without a breaking change; matches outside this crate need a wildcard arm.
 
 use std::fmt;
 
+use thiserror::Error;
+
 #[cfg(feature = "instrument")]
 use tracing::error;
 
 /// Represents errors that can occur during `TypeID` suffix decoding.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum DecodeError {
     /// Represents an error with the `TypeID` suffix.
+    #[error("{}", traced(format_args!("Invalid `TypeID` suffix: {}", .0)))]
     InvalidSuffix(InvalidSuffixReason),
     /// Represents an error with the underlying UUID.
+    #[error("{}", traced(format_args!("Invalid UUID: {}", .0)))]
     InvalidUuid(InvalidUuidReason),
+    /// Represents an error with the prefix of a full `TypeID` string.
+    #[error("{}", traced(format_args!("Invalid `TypeID` prefix: {}", .0)))]
+    InvalidPrefix(PrefixError),
+}
+
+impl DecodeError {
+    /// Boxes the error as a thread-safe trait object.
+    ///
+    /// This is a shorthand for the `Box<dyn Error + Send + Sync>` conversion that
+    /// `?` performs, for use where the target type is not inferred, such as when
+    /// returning errors across `await` points.
+    ///
+    /// # Returns
+    ///
+    /// The error as a `Box<dyn std::error::Error + Send + Sync + 'static>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let error = TypeIdSuffix::from_str("too_short").unwrap_err().into_boxed();
+    /// assert_eq!(error.to_string(), "Invalid `TypeID` suffix: Suffix must be exactly 26 characters long");
+    /// ```
+    #[must_use]
+    pub fn into_boxed(self) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+        Box::new(self)
+    }
 }
 
 /// Specifies the reason for an invalid `TypeID` suffix.
 ///
 /// This enum provides more granular information about why a `TypeID` suffix
 /// is considered invalid.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum InvalidSuffixReason {
     /// The suffix does not have the required length of 26 characters.
+    #[error("{}", traced("Suffix must be exactly 26 characters long"))]
     InvalidLength,
     /// The suffix contains one or more non-ASCII characters.
+    #[error("{}", traced("Suffix contains non-ASCII characters"))]
     NonAsciiCharacter,
     /// The first character of the suffix is greater than '7'.
+    #[error("{}", traced("First character of suffix must be '7' or less"))]
     InvalidFirstCharacter,
     /// The suffix contains a character that is not in the base32 alphabet.
+    #[error("{}", traced("Suffix contains characters not in the base32 alphabet"))]
     InvalidCharacter,
+    /// The suffix decodes to a UUID that re-encodes to a different string.
+    #[error("{}", traced("Suffix is not in canonical form"))]
+    NonCanonical,
 }
 
 /// Specifies the reason for an invalid UUID.
 ///
 /// This enum provides more detailed information about why a UUID
 /// is considered invalid in the context of `TypeID`.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
 pub enum InvalidUuidReason {
     /// The UUID version is not valid for this `TypeID`.
+    #[error("{}", traced("UUID version is not valid for this TypeID"))]
     InvalidVersion,
     /// The UUID variant is not RFC4122.
+    #[error("{}", traced("UUID variant is not RFC4122"))]
     InvalidVariant,
     /// The UUID bytes are invalid.
+    #[error("{}", traced("UUID bytes are invalid"))]
     InvalidBytes,
+    /// The UUID generator (clock or random number source) is unavailable.
+    #[error("{}", traced("UUID generation is unavailable in this environment"))]
+    GenerationUnavailable,
+    /// The UUID is nil, which the caller does not accept as an identifier.
+    #[error("{}", traced("UUID must not be nil"))]
+    NilNotAllowed,
 }
 
-impl std::fmt::Display for DecodeError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let msg = match self {
-            Self::InvalidSuffix(reason) => format!("Invalid `TypeID` suffix: {reason}"),
-            Self::InvalidUuid(reason) => format!("Invalid UUID: {reason}"),
-        };
-
-        #[cfg(feature = "instrument")]
-        error!("{msg}");
-
-        write!(f, "{msg}")
-    }
+/// Represents errors that can occur when validating a `TypeID` prefix.
+///
+/// A prefix is either empty or consists of at most 63 lowercase ASCII letters
+/// and underscores, starting and ending with a letter.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum PrefixError {
+    /// The prefix contains a character other than `a`-`z` or `_`.
+    #[error(
+        "{}",
+        traced(format_args!(
+            "Prefix must contain only lowercase ASCII letters and underscores (found an invalid character at position {position})"
+        ))
+    )]
+    InvalidCharacter {
+        /// The byte offset of the first invalid character.
+        position: usize,
+    },
+    /// The prefix starts with an underscore.
+    #[error("{}", traced("Prefix must not start with an underscore"))]
+    LeadingUnderscore,
+    /// The prefix ends with an underscore.
+    #[error("{}", traced("Prefix must not end with an underscore"))]
+    TrailingUnderscore,
+    /// The prefix is longer than 63 characters.
+    #[error("{}", traced("Prefix must be at most 63 characters long"))]
+    TooLong,
+    /// A `TypeID` string has an underscore separator with nothing before it.
+    ///
+    /// An empty prefix is written without the separator, so `validate_prefix`
+    /// accepts it; this is reported only when parsing full `TypeID` strings.
+    #[error("{}", traced("Prefix must not be empty when followed by an underscore separator"))]
+    EmptyWithSeparator,
 }
 
-impl std::fmt::Display for InvalidSuffixReason {
-    /// Provides a human-readable description of the invalid suffix reason.
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let msg = match self {
-            Self::InvalidLength => "Suffix must be exactly 26 characters long",
-            Self::NonAsciiCharacter => "Suffix contains non-ASCII characters",
-            Self::InvalidFirstCharacter => "First character of suffix must be '7' or less",
-            Self::InvalidCharacter => "Suffix contains characters not in the base32 alphabet",
-        };
-
-        #[cfg(feature = "instrument")]
-        error!("{}", msg);
-
-        write!(f, "{msg}")
-    }
+/// Represents errors that can occur when parsing a typed ID generated by `typed_id!`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum TypedIdError {
+    /// The suffix part of the `TypeID` is invalid.
+    #[error("{}", traced(.0))]
+    Decode(#[from] DecodeError),
+    /// The `TypeID` prefix does not match the typed ID's prefix.
+    #[error("{}", traced(format_args!("Expected prefix `{expected}`, found `{found}`")))]
+    PrefixMismatch {
+        /// The prefix the typed ID requires.
+        expected: &'static str,
+        /// The prefix that was found (empty if there was none).
+        found: String,
+    },
 }
 
-impl std::fmt::Display for InvalidUuidReason {
-    /// Provides a human-readable description of the invalid UUID reason.
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let msg = match self {
-            Self::InvalidVersion => "UUID version is not valid for this TypeID",
-            Self::InvalidVariant => "UUID variant is not RFC4122",
-            Self::InvalidBytes => "UUID bytes are invalid",
-        };
+/// Represents errors yielded by `io::decode_lines`.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum DecodeLinesError {
+    /// A line is not a valid `TypeID` suffix. The stream continues with the next line.
+    #[error("{}", traced(.0))]
+    Decode(#[from] DecodeError),
+    /// Reading from the underlying reader failed. The stream ends after this error.
+    #[error("{}", traced(format_args!("Failed to read line: {}", .0)))]
+    Io(#[from] std::io::Error),
+}
 
-        #[cfg(feature = "instrument")]
-        error!("{msg}");
+/// Passes an error message through, emitting it as a `tracing` error event when
+/// the `instrument` feature is enabled.
+///
+/// Every `#[error]` attribute above formats its message through this helper, so
+/// displaying any of these errors is logged from one place.
+#[inline]
+#[allow(clippy::missing_const_for_fn)]
+fn traced<T: fmt::Display>(message: T) -> T {
+    #[cfg(feature = "instrument")]
+    error!("{message}");
 
-        write!(f, "{msg}")
-    }
+    message
 }
-
-/// Implement the standard Error trait for `DecodeError`.
-impl std::error::Error for DecodeError {}
\ No newline at end of file
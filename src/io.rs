@@ -0,0 +1,72 @@
+//! Streaming helpers for reading ``TypeIdSuffix`` values.
+//!
+//! Newline-delimited files of suffixes can be very large. These helpers parse
+//! them lazily, one line at a time, instead of collecting every line first.
+
+use std::io::BufRead;
+
+use crate::errors::{DecodeError, DecodeLinesError, InvalidSuffixReason};
+use crate::typeid_suffix::TypeIdSuffix;
+
+/// Lazily parses one ``TypeIdSuffix`` per line from a reader.
+///
+/// Trailing `\n` and `\r\n` line endings are removed and empty lines are
+/// skipped. Lines are read as raw bytes, so invalid UTF-8 produces a
+/// `DecodeLinesError::Decode` for that line rather than stopping the stream.
+///
+/// Iteration ends at the end of the input. An I/O error from the reader is
+/// yielded as `DecodeLinesError::Io` and also ends iteration, so a failed read
+/// is never mistaken for a shorter input.
+///
+/// # Arguments
+///
+/// * `reader`: The buffered reader to parse.
+///
+/// # Returns
+///
+/// An iterator yielding the parse result of each non-empty line, followed by
+/// the I/O error if reading fails.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+/// use typeid_suffix::io::decode_lines;
+///
+/// let input = Cursor::new("01h455vb4pex5vsknk084sn02q\r\n\nnot-a-suffix\n");
+/// let results: Vec<_> = decode_lines(input).collect();
+/// assert_eq!(results.len(), 2);
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+pub fn decode_lines<R: BufRead>(mut reader: R) -> impl Iterator<Item = Result<TypeIdSuffix, DecodeLinesError>> {
+    let mut line = Vec::with_capacity(28);
+    let mut failed = false;
+    std::iter::from_fn(move || loop {
+        if failed {
+            return None;
+        }
+        line.clear();
+        match reader.read_until(b'\n', &mut line) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(error) => {
+                failed = true;
+                return Some(Err(DecodeLinesError::Io(error)));
+            }
+        }
+        let trimmed = line.strip_suffix(b"\n").unwrap_or(&line);
+        let trimmed = trimmed.strip_suffix(b"\r").unwrap_or(trimmed);
+        if trimmed.is_empty() {
+            continue;
+        }
+        return Some(
+            <[u8; 26]>::try_from(trimmed)
+                .map_or(
+                    Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength)),
+                    TypeIdSuffix::from_array,
+                )
+                .map_err(DecodeLinesError::Decode),
+        );
+    })
+}
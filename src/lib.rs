@@ -32,7 +32,7 @@
 //! ### Creating a `TypeID` Suffix
 //!
 //! By default, calling `TypeIdSuffix::default()` produces a suffix made from a `UUIDv7`
-//! using the current timestamp.
+//! using the current timestamp (or a `UUIDv4` with the `default-v4` feature).
 //!
 //! ```rust
 //! use typeid_suffix::prelude::*;
@@ -92,7 +92,35 @@
 //! ## Optional Features
 //!
 //! - `instrument`: Enables logging with the `tracing` crate.
-//! - `serde`: Enables serialization and deserialization support using the `serde` crate.
+//! - `arrayvec`: Adds `to_array_string`, an allocation-free `arrayvec::ArrayString<26>` rendering.
+//! - `bench-internals`: Exposes the base32 encoder and decoder in a hidden `bench` module for
+//!   `benches/encoding.rs`. This is not part of the stable API.
+//! - `borsh`: Implements `BorshSerialize` and `BorshDeserialize` using the 16 decoded UUID bytes.
+//! - `clap`: Adds `TypeIdSuffixValueParser`, a `clap` value parser that validates suffix arguments at parse time.
+//! - `conformance`: Adds `conformance::from_reference_json` for checking the reference implementation's JSON test vectors.
+//! - `ct`: Adds `ct_eq` and implements `subtle::ConstantTimeEq` for constant-time comparison.
+//! - `default-v4`: Makes `TypeIdSuffix::default()` generate a `UUIDv4` instead of a `UUIDv7`.
+//!   Takes precedence over `default-v7` (see [Default Version](#default-version)).
+//! - `default-v7` (enabled by default): Makes `TypeIdSuffix::default()` generate a `UUIDv7`.
+//! - `diesel`: Implements Diesel's `ToSql` and `FromSql` for `Text` columns, plus `AsExpression` and `FromSqlRow`.
+//! - `metrics`: Counts `from_str` failures in a `typeid_suffix_decode_errors_total` counter via the
+//!   `metrics` facade, labeled with the failure `reason`.
+//! - `miette`: Implements `miette::Diagnostic` for `DecodeError`, with a stable code and help message per reason.
+//! - `postgres`: Implements `ToSql` and `FromSql` from `postgres-types` for text and `UUID` columns.
+//! - `redis`: Implements `ToRedisArgs` and `FromRedisValue` so suffixes can be used as Redis keys and values.
+//! - `rkyv`: Implements zero-copy archiving with `rkyv`, validating archived suffixes with `bytecheck`.
+//! - `schemars`: Implements `schemars::JsonSchema`, describing the suffix as a pattern-constrained string.
+//! - `serde`: Enables serialization and deserialization support using the `serde` crate,
+//!   plus the `serde_prefixed` helpers for full `prefix_suffix` strings and the
+//!   `serde_uuid_bytes` helpers for fields stored as 16 UUID bytes.
+//! - `simd`: Uses AVX2 for base32 encoding and decoding on `x86_64` CPUs that support it,
+//!   detected at runtime. Other targets and CPUs use the scalar implementation.
+//! - `sqlx-postgres`: Implements `sqlx::Type`, `Encode`, and `Decode` for `Postgres` text columns.
+//! - `sqlx-sqlite`: Implements `sqlx::Type`, `Encode`, and `Decode` for `SQLite` text columns.
+//! - `strict-global`: Makes every parsing path (including `from_str` and serde) reject UUIDs
+//!   that are not RFC4122 variant with a version from 1 to 8.
+//! - `wasm`: Exposes `generate_v7`, `validate`, and `to_uuid` to JavaScript via `wasm-bindgen`.
+//! - `zeroize`: Implements `zeroize::Zeroize`, scrubbing the encoded bytes (which leaves an invalid value).
 //!
 //! To enable optional features, add them to your `Cargo.toml`:
 //!
@@ -134,6 +162,42 @@
 //! # }
 //! ```
 //!
+//! ### Strict Validation
+//!
+//! By default, `TypeIdSuffix` accepts any 26-character suffix that decodes to a
+//! 128-bit value, as the `TypeID` specification requires. Enabling the
+//! `strict-global` feature tightens every parsing path in the crate (`from_str`,
+//! `from_array`, serde deserialization, and so on) to accept only RFC4122-variant
+//! UUIDs with a version from 1 to 8.
+//!
+//! This is a trade-off: strict mode catches IDs that were not produced by a
+//! standard UUID generator, but it is no longer specification-compliant. The nil
+//! UUID and several of the specification's test vectors are rejected. Because
+//! Cargo features are unified across a dependency graph, enabling the feature in
+//! one crate changes parsing behavior for every crate in the build that uses
+//! `typeid_suffix`. Conversions from `Uuid` are infallible and are not affected.
+//!
+//! To opt in at a single call site instead, use `TypeIdSuffixBuilder`:
+//!
+//! ```rust
+//! use typeid_suffix::prelude::*;
+//!
+//! let parser = TypeIdSuffixBuilder::new().strict(true);
+//! assert!(parser.parse("01h455vb4pex5vsknk084sn02q").is_ok());
+//! assert!(parser.parse("00000000000000000000000000").is_err());
+//! ```
+//!
+//! ### Default Version
+//!
+//! `TypeIdSuffix::default()` generates a `UUIDv7`, which sorts by creation time
+//! but embeds a millisecond timestamp that anyone holding the ID can read.
+//! Teams that consider creation time sensitive can enable `default-v4` to make
+//! `default()` generate a random `UUIDv4` instead, without changing call sites.
+//! `new::<V>()` is unaffected and always generates the version it names.
+//!
+//! `default-v7` is enabled by default, so `default-v4` must take precedence when
+//! both are enabled; this also keeps `--all-features` builds working.
+//!
 //! ## License
 //!
 //! This project is licensed under either of
@@ -147,12 +211,69 @@
 //!
 //! Contributions are welcome! Please feel free to submit a Pull Request.
 
+// Library code must not panic through `unwrap` or `expect`, so that the crate
+// builds cleanly for users who deny these lints across their dependencies
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used))]
+
+pub mod batch;
+#[cfg(feature = "bench-internals")]
+#[doc(hidden)]
+pub mod bench;
+mod builder;
+#[cfg(feature = "conformance")]
+pub mod conformance;
+pub mod encoding;
 mod errors;
-mod encoding;
+pub mod io;
+mod ordering;
+pub mod prefix;
+pub mod registry;
+#[cfg(feature = "serde")]
+pub mod serde_prefixed;
+#[cfg(feature = "serde")]
+pub mod serde_uuid_bytes;
 
+mod spec;
+mod typed_id;
 mod typeid_suffix;
 mod versions;
 
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "clap")]
+mod clap;
+#[cfg(feature = "ct")]
+mod ct;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "miette")]
+mod miette;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "schemars")]
+mod schemars;
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd;
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+mod sqlx;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+/// Re-exports used by macro expansions. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
+
 /// The prelude module provides a convenient way to import commonly used items.
 ///
 /// By adding `use typeid_suffix::prelude::*;` to your code, you can easily
@@ -160,11 +281,39 @@ mod versions;
 pub mod prelude {
     pub use uuid::{Uuid, Version};
 
+    pub use crate::builder::TypeIdSuffixBuilder;
+    #[cfg(feature = "clap")]
+    pub use crate::clap::TypeIdSuffixValueParser;
     pub use crate::errors::*;
+    pub use crate::ordering::TimestampOrderedExt;
+    pub use crate::spec::SpecVersion;
     pub use crate::typeid_suffix::TypeIdSuffix;
+    #[cfg(feature = "rkyv")]
+    pub use crate::typeid_suffix::ArchivedTypeIdSuffix;
     pub use crate::versions::*;
 }
 
+#[cfg(test)]
+mod panic_free_tests {
+    #![deny(clippy::unwrap_used, clippy::expect_used)]
+
+    use crate::prelude::*;
+
+    #[test]
+    fn test_to_uuid_and_as_str_without_unwrap() {
+        let cases = [
+            (Uuid::nil(), "00000000000000000000000000"),
+            (Uuid::max(), "7zzzzzzzzzzzzzzzzzzzzzzzzz"),
+            (Uuid::from_u128(0x0189_0a5d_ac96_774b_bcce_b302_099a_8057), "01h455vb4pex5vsknk084sn02q"),
+        ];
+        for (uuid, encoded) in cases {
+            let suffix = TypeIdSuffix::from(uuid);
+            assert_eq!(suffix.to_uuid(), uuid);
+            assert_eq!(&*suffix, encoded);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -178,15 +327,70 @@ mod tests {
     fn test_typeid_suffix_default() {
         let suffix = TypeIdSuffix::default();
         let uuid: Uuid = suffix.into();
+        #[cfg(not(feature = "default-v4"))]
         assert_eq!(uuid.get_version(), Some(uuid::Version::SortRand));
+        #[cfg(feature = "default-v4")]
+        assert_eq!(uuid.get_version(), Some(uuid::Version::Random));
     }
 
 
+    #[test]
+    fn test_next_v7_carries_and_saturates() {
+        use crate::batch::next_v7;
+
+        // A full counter carries into the next millisecond
+        let full = Uuid::from_u128(0x0000_0000_0001_7fff_bfff_ffff_ffff_ffff);
+        assert_eq!(next_v7(&full), Uuid::from_u128(0x0000_0000_0002_7000_8000_0000_0000_0000));
+
+        // The largest `UUIDv7` has nowhere left to go and is returned unchanged
+        let max = Uuid::from_u128(0xffff_ffff_ffff_7fff_bfff_ffff_ffff_ffff);
+        assert_eq!(next_v7(&max), max);
+        assert_eq!(max.get_version(), Some(uuid::Version::SortRand));
+    }
+
     #[test]
     fn test_typeid_suffix_explicit_version() {
         let _suffix = TypeIdSuffix::new::<V4>();
     }
 
+    #[test]
+    fn test_decode_base32_overflow() {
+        use crate::encoding::decode_base32;
+
+        assert_eq!(
+            decode_base32(b"zzzzzzzzzzzzzzzzzzzzzzzzzz"),
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter))
+        );
+        assert_eq!(
+            decode_base32(b"80000000000000000000000000"),
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter))
+        );
+        assert_eq!(decode_base32(b"7zzzzzzzzzzzzzzzzzzzzzzzzz"), Ok([0xFF; 16]));
+    }
+
+    #[test]
+    fn test_generic_encoding_eight_bytes() {
+        use crate::encoding::{decode, encode};
+
+        // 8 bytes need 13 characters (65 bits), so the first character is at most '1'
+        let bytes = 0x0123_4567_89ab_cdefu64.to_be_bytes();
+        let encoded: [u8; 13] = encode(&bytes);
+        assert_eq!(&encoded, b"028t5cy4tqkff");
+        assert_eq!(decode::<8, 13>(&encoded), Ok(bytes));
+
+        assert_eq!(&encode::<8, 13>(&[0x00; 8]), b"0000000000000");
+        assert_eq!(&encode::<8, 13>(&[0xFF; 8]), b"fzzzzzzzzzzzz");
+        assert_eq!(decode::<8, 13>(b"fzzzzzzzzzzzz"), Ok([0xFF; 8]));
+        assert_eq!(
+            decode::<8, 13>(b"g000000000000"),
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter))
+        );
+        assert_eq!(
+            decode::<8, 13>(b"000000000000u"),
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidCharacter))
+        );
+    }
+
     prop_compose! {
     fn arbitrary_uuidv7()(timestamp in 0..=0xFFFF_FFFF_FFFF_FFFFu64, counter in 0..0x3FFFu16) -> Uuid {
         let mut bytes = [0u8; 16];
@@ -235,6 +439,70 @@ mod tests {
         }
     }
 
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    mod simd_tests {
+        use super::*;
+        use crate::encoding::{decode, encode};
+
+        // Runs `check` only when the CPU supports AVX2, since the SIMD path cannot run otherwise
+        fn with_avx2(check: impl FnOnce()) {
+            if std::arch::is_x86_feature_detected!("avx2") {
+                check();
+            }
+        }
+
+        #[test]
+        fn test_simd_matches_scalar_for_every_byte_at_every_position() {
+            with_avx2(|| {
+                for position in 0..26 {
+                    for byte in 0..=u8::MAX {
+                        let mut encoded = *b"01h455vb4pex5vsknk084sn02q";
+                        encoded[position] = byte;
+                        // SAFETY: AVX2 support was detected by `with_avx2`
+                        #[allow(unsafe_code)]
+                        let simd = unsafe { crate::simd::decode_base32(&encoded) };
+                        assert_eq!(simd, decode::<16, 26>(&encoded), "byte {byte:#04x} at {position}");
+                    }
+                }
+            });
+        }
+
+        #[test]
+        fn test_simd_extremes() {
+            with_avx2(|| {
+                for bytes in [[0x00; 16], [0xFF; 16], [0x80; 16], [0x01; 16]] {
+                    // SAFETY: AVX2 support was detected by `with_avx2`
+                    #[allow(unsafe_code)]
+                    let simd = unsafe { crate::simd::encode_base32(&bytes) };
+                    assert_eq!(simd, encode::<16, 26>(&bytes));
+                }
+            });
+        }
+
+        proptest! {
+            #[test]
+            fn test_simd_encode_matches_scalar(bytes in proptest::array::uniform16(any::<u8>())) {
+                with_avx2(|| {
+                    // SAFETY: AVX2 support was detected by `with_avx2`
+                    #[allow(unsafe_code)]
+                    let simd = unsafe { crate::simd::encode_base32(&bytes) };
+                    assert_eq!(simd, encode::<16, 26>(&bytes));
+                });
+            }
+
+            #[test]
+            fn test_simd_decode_matches_scalar(s in "[0-9a-z]{26}") {
+                let encoded: [u8; 26] = s.as_bytes().try_into().unwrap();
+                with_avx2(|| {
+                    // SAFETY: AVX2 support was detected by `with_avx2`
+                    #[allow(unsafe_code)]
+                    let simd = unsafe { crate::simd::decode_base32(&encoded) };
+                    assert_eq!(simd, decode::<16, 26>(&encoded));
+                });
+            }
+        }
+    }
+
     proptest! {
 
         #[test]
@@ -270,6 +538,62 @@ mod tests {
             prop_assert_eq!(suffix, from_str);
         }
 
+        #[test]
+        fn test_array_roundtrip(uuid in arbitrary_uuidv7()) {
+            let suffix: TypeIdSuffix = uuid.into();
+            prop_assert_eq!(TypeIdSuffix::from_array(suffix.to_array()), Ok(suffix));
+        }
+
+        #[test]
+        fn test_array_other_roundtrip(uuid in arbitrary_uuid_other()) {
+            let suffix: TypeIdSuffix = uuid.into();
+            prop_assert_eq!(TypeIdSuffix::from_array(suffix.to_array()), Ok(suffix));
+        }
+
+        #[test]
+        fn test_uppercase_roundtrip(uuid in arbitrary_uuid_other()) {
+            let suffix: TypeIdSuffix = uuid.into();
+            let upper = suffix.to_uppercase_string();
+            prop_assert!(TypeIdSuffix::from_str(&upper).is_err() || upper == suffix.to_string());
+            prop_assert_eq!(TypeIdSuffix::from_str_lenient(&upper), Ok(suffix));
+        }
+
+        #[test]
+        fn test_generic_encoding_roundtrip(value in any::<u64>(), small in any::<u16>()) {
+            use crate::encoding::{decode, encode};
+
+            let bytes = value.to_be_bytes();
+            prop_assert_eq!(decode::<8, 13>(&encode::<8, 13>(&bytes)), Ok(bytes));
+            let bytes = small.to_be_bytes();
+            prop_assert_eq!(decode::<2, 4>(&encode::<2, 4>(&bytes)), Ok(bytes));
+        }
+
+        #[test]
+        fn test_canonical_matches_from_str(s in "[0-7][0-9a-hjkmnp-tv-z]{25}") {
+            prop_assert_eq!(TypeIdSuffix::from_str_canonical(&s), TypeIdSuffix::from_str(&s));
+        }
+
+        #[test]
+        fn test_ord_matches_uuid_ord(a in arbitrary_uuid_other(), b in arbitrary_uuidv7()) {
+            let (sa, sb): (TypeIdSuffix, TypeIdSuffix) = (a.into(), b.into());
+            prop_assert_eq!(sa.cmp(&sb), a.cmp(&b));
+        }
+
+        #[test]
+        fn test_u64_pair_roundtrip(uuid in arbitrary_uuid_other()) {
+            let suffix = TypeIdSuffix::from(uuid);
+            let (high, low) = suffix.to_u64_pair();
+            prop_assert_eq!((u128::from(high) << 64) | u128::from(low), suffix.to_u128());
+            prop_assert_eq!(TypeIdSuffix::from_u64_pair(high, low), suffix);
+        }
+
+        #[test]
+        fn test_sort_key_matches_ord(a in proptest::array::uniform16(any::<u8>()), b in proptest::array::uniform16(any::<u8>())) {
+            let (sa, sb) = (TypeIdSuffix::from(Uuid::from_bytes(a)), TypeIdSuffix::from(Uuid::from_bytes(b)));
+            prop_assert_eq!(sa.sort_key().cmp(&sb.sort_key()), sa.cmp(&sb));
+            prop_assert_eq!(sa < sb, sa.sort_key() < sb.sort_key());
+        }
+
         #[test]
         fn test_invalid_suffix(s in "[0-9a-zA-Z]{26}") {
             if s.as_bytes()[0] > b'7' {
@@ -280,4 +604,4 @@ mod tests {
 
 
     }
-}
\ No newline at end of file
+}
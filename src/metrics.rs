@@ -0,0 +1,33 @@
+//! `metrics` support for ``TypeIdSuffix`` parsing.
+//!
+//! Every `from_str` failure, including `TryFrom<String>`, which delegates to
+//! it, increments the `typeid_suffix_decode_errors_total` counter through the
+//! `metrics` facade. The counter carries a `reason` label naming the
+//! `InvalidSuffixReason` or `InvalidUuidReason` variant, such as
+//! `InvalidLength`, so malformed IDs can be tracked by cause. Without an
+//! installed recorder, the facade discards the increments.
+
+use crate::errors::{DecodeError, InvalidSuffixReason, InvalidUuidReason};
+
+impl DecodeError {
+    const fn reason_label(&self) -> &'static str {
+        match self {
+            Self::InvalidSuffix(InvalidSuffixReason::InvalidLength) => "InvalidLength",
+            Self::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter) => "NonAsciiCharacter",
+            Self::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter) => "InvalidFirstCharacter",
+            Self::InvalidSuffix(InvalidSuffixReason::InvalidCharacter) => "InvalidCharacter",
+            Self::InvalidSuffix(InvalidSuffixReason::NonCanonical) => "NonCanonical",
+            Self::InvalidUuid(InvalidUuidReason::InvalidVersion) => "InvalidVersion",
+            Self::InvalidUuid(InvalidUuidReason::InvalidVariant) => "InvalidVariant",
+            Self::InvalidUuid(InvalidUuidReason::InvalidBytes) => "InvalidBytes",
+            Self::InvalidUuid(InvalidUuidReason::GenerationUnavailable) => "GenerationUnavailable",
+            Self::InvalidUuid(InvalidUuidReason::NilNotAllowed) => "NilNotAllowed",
+            Self::InvalidPrefix(_) => "InvalidPrefix",
+        }
+    }
+}
+
+/// Increments the decode error counter for `error`.
+pub fn record_decode_error(error: &DecodeError) {
+    ::metrics::counter!("typeid_suffix_decode_errors_total", "reason" => error.reason_label()).increment(1);
+}
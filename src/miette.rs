@@ -0,0 +1,73 @@
+//! `miette` support for `DecodeError`.
+//!
+//! Each failure reason maps to a stable diagnostic code of the form
+//! `typeid_suffix::<reason>` and a help message suggesting the fix, so CLI
+//! tools can render parse failures with `miette`'s reporters.
+
+use std::fmt::Display;
+
+use ::miette::Diagnostic;
+
+use crate::errors::{DecodeError, InvalidSuffixReason, InvalidUuidReason};
+
+impl DecodeError {
+    const fn diagnostic_code(&self) -> &'static str {
+        match self {
+            Self::InvalidSuffix(InvalidSuffixReason::InvalidLength) => "typeid_suffix::invalid_length",
+            Self::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter) => "typeid_suffix::non_ascii_character",
+            Self::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter) => "typeid_suffix::invalid_first_character",
+            Self::InvalidSuffix(InvalidSuffixReason::InvalidCharacter) => "typeid_suffix::invalid_character",
+            Self::InvalidSuffix(InvalidSuffixReason::NonCanonical) => "typeid_suffix::non_canonical",
+            Self::InvalidUuid(InvalidUuidReason::InvalidVersion) => "typeid_suffix::invalid_version",
+            Self::InvalidUuid(InvalidUuidReason::InvalidVariant) => "typeid_suffix::invalid_variant",
+            Self::InvalidUuid(InvalidUuidReason::InvalidBytes) => "typeid_suffix::invalid_bytes",
+            Self::InvalidUuid(InvalidUuidReason::GenerationUnavailable) => "typeid_suffix::generation_unavailable",
+            Self::InvalidUuid(InvalidUuidReason::NilNotAllowed) => "typeid_suffix::nil_not_allowed",
+            Self::InvalidPrefix(_) => "typeid_suffix::invalid_prefix",
+        }
+    }
+
+    const fn diagnostic_help(&self) -> &'static str {
+        match self {
+            Self::InvalidSuffix(InvalidSuffixReason::InvalidLength) => {
+                "suffixes must be exactly 26 lowercase base32 characters"
+            }
+            Self::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter) => {
+                "remove any non-ASCII characters; suffixes use only `0-9` and lowercase `a-z`"
+            }
+            Self::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter) => {
+                "the first character must be between `0` and `7`, or the value exceeds 128 bits"
+            }
+            Self::InvalidSuffix(InvalidSuffixReason::InvalidCharacter) => {
+                "use only the lowercase base32 alphabet `0123456789abcdefghjkmnpqrstvwxyz`"
+            }
+            Self::InvalidSuffix(InvalidSuffixReason::NonCanonical) => {
+                "re-encode the suffix from its UUID to obtain the canonical form"
+            }
+            Self::InvalidUuid(InvalidUuidReason::InvalidVersion) => {
+                "encode a UUID with a supported version, such as `UUIDv7`"
+            }
+            Self::InvalidUuid(InvalidUuidReason::InvalidVariant) => "encode an RFC4122 variant UUID",
+            Self::InvalidUuid(InvalidUuidReason::InvalidBytes) => "encode a well-formed 16-byte UUID",
+            Self::InvalidUuid(InvalidUuidReason::GenerationUnavailable) => {
+                "ensure a system clock and random number source are available, or build the suffix from an existing UUID"
+            }
+            Self::InvalidUuid(InvalidUuidReason::NilNotAllowed) => {
+                "a nil suffix usually means an uninitialized value; supply a generated ID"
+            }
+            Self::InvalidPrefix(_) => {
+                "prefixes are empty (written without the separator) or up to 63 lowercase letters and underscores, starting and ending with a letter"
+            }
+        }
+    }
+}
+
+impl Diagnostic for DecodeError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(self.diagnostic_code()))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(self.diagnostic_help()))
+    }
+}
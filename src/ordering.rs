@@ -0,0 +1,40 @@
+//! Ordering helpers for collections of ``TypeIdSuffix`` values.
+//!
+//! The `Ord` implementation compares suffixes byte by byte, which matches
+//! creation order only when every suffix is a `UUIDv7`. These helpers order
+//! mixed collections by the time embedded in their `UUIDv7` suffixes instead.
+
+use crate::typeid_suffix::TypeIdSuffix;
+
+/// Sorts collections of ``TypeIdSuffix`` values by their embedded creation time.
+pub trait TimestampOrderedExt {
+    /// Sorts `UUIDv7` suffixes by `timestamp_millis`, placing all other suffixes after them.
+    ///
+    /// The sort is stable: suffixes with equal timestamps, and the trailing
+    /// suffixes without a timestamp, keep their original relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let random = TypeIdSuffix::new::<V4>();
+    /// let later = TypeIdSuffix::from(uuid::Builder::from_unix_timestamp_millis(2_000, &[0; 10]).into_uuid());
+    /// let earlier = TypeIdSuffix::from(uuid::Builder::from_unix_timestamp_millis(1_000, &[0; 10]).into_uuid());
+    ///
+    /// let mut suffixes = vec![random.clone(), later.clone(), earlier.clone()];
+    /// suffixes.sort_by_timestamp();
+    /// assert_eq!(suffixes, [earlier, later, random]);
+    /// ```
+    fn sort_by_timestamp(&mut self);
+}
+
+impl TimestampOrderedExt for [TypeIdSuffix] {
+    fn sort_by_timestamp(&mut self) {
+        // `None` sorts before `Some`, so order on whether the timestamp is missing first
+        self.sort_by_cached_key(|suffix| {
+            let millis = suffix.timestamp_millis();
+            (millis.is_none(), millis)
+        });
+    }
+}
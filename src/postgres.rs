@@ -0,0 +1,54 @@
+//! `postgres-types` support for ``TypeIdSuffix``.
+//!
+//! This lets ``TypeIdSuffix`` be used directly as a `tokio-postgres`/`postgres`
+//! query parameter or column value. Text columns (`TEXT`, `VARCHAR`, ...) store the
+//! 26-character suffix and are validated on read; `UUID` columns store the 16
+//! decoded bytes, which are validated with `from_uuid`.
+
+use std::error::Error;
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+use uuid::Uuid;
+
+use crate::typeid_suffix::TypeIdSuffix;
+
+impl ToSql for TypeIdSuffix {
+    /// Writes the ``TypeIdSuffix`` as a UUID for `UUID` columns and as its
+    /// string representation for text columns.
+    fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        if *ty == Type::UUID {
+            self.to_uuid().to_sql(ty, out)
+        } else {
+            self.as_ref().to_sql(ty, out)
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Uuid as ToSql>::accepts(ty) || <&str as ToSql>::accepts(ty)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for TypeIdSuffix {
+    /// Reads a ``TypeIdSuffix`` from a `UUID` column or a text column.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `DecodeError` produced by `from_str` if a text value is not
+    /// a valid ``TypeIdSuffix``, or by `from_uuid` if a `UUID` value is rejected
+    /// (which only happens with the `strict-global` feature).
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        if *ty == Type::UUID {
+            Ok(Self::from_uuid(Uuid::from_sql(ty, raw)?)?)
+        } else {
+            Ok(Self::from_str(<&str as FromSql>::from_sql(ty, raw)?)?)
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <Uuid as FromSql>::accepts(ty) || <&str as FromSql>::accepts(ty)
+    }
+}
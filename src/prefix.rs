@@ -0,0 +1,66 @@
+//! Validation of `TypeID` prefixes.
+//!
+//! A `TypeID` is a prefix and a suffix joined by an underscore. This crate only
+//! models the suffix, but the prefix rules are needed wherever full `TypeID`
+//! strings are produced, so they are exposed here for use on their own.
+
+pub use crate::errors::PrefixError;
+
+/// The maximum length of a `TypeID` prefix, in characters.
+pub const MAX_PREFIX_LEN: usize = 63;
+
+/// Checks a `TypeID` prefix against the specification.
+///
+/// An empty prefix is valid and denotes a `TypeID` without one. Otherwise the
+/// prefix must be at most 63 characters long, contain only lowercase ASCII
+/// letters and underscores, and start and end with a letter.
+///
+/// # Arguments
+///
+/// * `prefix`: The prefix to validate.
+///
+/// # Returns
+///
+/// `Ok(())` if the prefix is valid.
+///
+/// # Errors
+///
+/// Returns `PrefixError::TooLong` if the prefix is longer than 63 bytes,
+/// `PrefixError::InvalidCharacter` with the byte offset of the first character
+/// outside `a`-`z` and `_`, or `PrefixError::LeadingUnderscore` or
+/// `PrefixError::TrailingUnderscore` if the prefix starts or ends with an
+/// underscore.
+///
+/// This is a `const fn`, so a prefix fixed at compile time, such as the one
+/// given to `typed_id!`, can be checked during the build.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::prefix::{validate_prefix, PrefixError};
+///
+/// assert!(validate_prefix("pre_fix").is_ok());
+/// assert!(validate_prefix("").is_ok());
+/// assert_eq!(validate_prefix("user1"), Err(PrefixError::InvalidCharacter { position: 4 }));
+/// assert_eq!(validate_prefix("_user"), Err(PrefixError::LeadingUnderscore));
+/// ```
+pub const fn validate_prefix(prefix: &str) -> Result<(), PrefixError> {
+    let bytes = prefix.as_bytes();
+    if bytes.len() > MAX_PREFIX_LEN {
+        return Err(PrefixError::TooLong);
+    }
+    let mut position = 0;
+    while position < bytes.len() {
+        if !(bytes[position].is_ascii_lowercase() || bytes[position] == b'_') {
+            return Err(PrefixError::InvalidCharacter { position });
+        }
+        position += 1;
+    }
+    if let [b'_', ..] = bytes {
+        return Err(PrefixError::LeadingUnderscore);
+    }
+    if let [.., b'_'] = bytes {
+        return Err(PrefixError::TrailingUnderscore);
+    }
+    Ok(())
+}
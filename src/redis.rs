@@ -0,0 +1,36 @@
+//! `redis` support for ``TypeIdSuffix``.
+//!
+//! ``TypeIdSuffix`` is written to Redis as its 26-character ASCII string, so it
+//! can be used directly as a key or value. Values read back are validated with
+//! `from_str`.
+
+use std::str::FromStr;
+
+use ::redis::{ErrorKind, FromRedisValue, RedisError, RedisResult, RedisWrite, ToRedisArgs, Value};
+
+use crate::typeid_suffix::TypeIdSuffix;
+
+impl ToRedisArgs for TypeIdSuffix {
+    /// Writes the ``TypeIdSuffix`` as its 26-character string representation.
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(self.as_bytes());
+    }
+}
+
+impl FromRedisValue for TypeIdSuffix {
+    /// Reads a ``TypeIdSuffix`` from a Redis string value.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `RedisError` of kind `TypeError` if the value is not a string
+    /// or is not a valid ``TypeIdSuffix``.
+    fn from_redis_value(v: &Value) -> RedisResult<Self> {
+        let s = String::from_redis_value(v)?;
+        Self::from_str(&s).map_err(|e| {
+            RedisError::from((ErrorKind::TypeError, "Invalid TypeIdSuffix", e.to_string()))
+        })
+    }
+}
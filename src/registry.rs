@@ -0,0 +1,73 @@
+//! Helpers for loading `prefix -> suffix` registries.
+//!
+//! Configuration files often map well-known names to fixed suffixes. This
+//! module parses such mappings in one step, reporting which entry failed.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::errors::DecodeError;
+use crate::typeid_suffix::TypeIdSuffix;
+
+/// Represents a registry entry whose suffix failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryError {
+    /// The key of the failing entry.
+    pub key: String,
+    /// The underlying decode error.
+    pub error: DecodeError,
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Registry entry `{}` is invalid: {}", self.key, self.error)
+    }
+}
+
+impl std::error::Error for RegistryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Parses `(key, suffix)` pairs into a map of ``TypeIdSuffix`` values.
+///
+/// Entries are parsed in iteration order and parsing stops at the first
+/// invalid suffix. If a key appears more than once, the last value wins.
+///
+/// # Arguments
+///
+/// * `iter`: The `(key, suffix)` string pairs to parse.
+///
+/// # Returns
+///
+/// A `Result` containing either the parsed map or a `RegistryError`.
+///
+/// # Errors
+///
+/// Returns a `RegistryError` carrying the failing key and its `DecodeError`
+/// if any suffix fails `from_str`.
+///
+/// # Examples
+///
+/// ```
+/// use typeid_suffix::registry::parse_registry;
+///
+/// let registry = parse_registry([("system", "01h455vb4pex5vsknk084sn02q")]).unwrap();
+/// assert_eq!(registry["system"].to_string(), "01h455vb4pex5vsknk084sn02q");
+///
+/// let error = parse_registry([("broken", "not-a-suffix")]).unwrap_err();
+/// assert_eq!(error.key, "broken");
+/// ```
+pub fn parse_registry<'a, I>(iter: I) -> Result<HashMap<String, TypeIdSuffix>, RegistryError>
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    iter.into_iter()
+        .map(|(key, value)| match TypeIdSuffix::from_str(value) {
+            Ok(suffix) => Ok((key.to_owned(), suffix)),
+            Err(error) => Err(RegistryError { key: key.to_owned(), error }),
+        })
+        .collect()
+}
@@ -0,0 +1,35 @@
+//! `schemars` support for ``TypeIdSuffix``.
+//!
+//! ``TypeIdSuffix`` is described as a 26-character string whose `pattern`
+//! accepts exactly what `from_str` accepts: a first character of `0`-`7`
+//! followed by 25 characters from the base32 alphabet (which omits `i`, `l`, `o`, and `u`).
+
+use std::borrow::Cow;
+
+use ::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::typeid_suffix::TypeIdSuffix;
+
+impl JsonSchema for TypeIdSuffix {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "TypeIdSuffix".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "typeid_suffix::TypeIdSuffix".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "description": "A `TypeID` suffix: a 26-character base32-encoded UUID.",
+            "pattern": "^[0-7][0-9a-hjkmnp-tv-z]{25}$",
+            "minLength": 26,
+            "maxLength": 26,
+        })
+    }
+}
@@ -0,0 +1,86 @@
+//! Serde helpers for serializing a ``TypeIdSuffix`` as a full, prefixed `TypeID`.
+//!
+//! A ``TypeIdSuffix`` normally serializes as the bare 26-character suffix. APIs
+//! that expose full `TypeID`s (`user_01h455vb4pex5vsknk084sn02q`) can keep the
+//! field typed as ``TypeIdSuffix`` and use these functions with
+//! `serialize_with`/`deserialize_with`. The prefix is supplied by a type that
+//! implements `Prefix`:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use typeid_suffix::prelude::*;
+//! use typeid_suffix::serde_prefixed::{self, Prefix};
+//!
+//! struct User;
+//!
+//! impl Prefix for User {
+//!     const PREFIX: &'static str = "user";
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Account {
+//!     #[serde(
+//!         serialize_with = "serde_prefixed::serialize::<User, _>",
+//!         deserialize_with = "serde_prefixed::deserialize::<User, _>"
+//!     )]
+//!     id: TypeIdSuffix,
+//! }
+//!
+//! let json = r#"{"id":"user_01h455vb4pex5vsknk084sn02q"}"#;
+//! let account: Account = serde_json::from_str(json).unwrap();
+//! assert_eq!(account.id.to_string(), "01h455vb4pex5vsknk084sn02q");
+//! assert_eq!(serde_json::to_string(&account).unwrap(), json);
+//! ```
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::typeid_suffix::TypeIdSuffix;
+
+/// Supplies the `TypeID` prefix used by `serialize` and `deserialize`.
+///
+/// An empty prefix serializes the bare suffix and only accepts unprefixed input.
+pub trait Prefix {
+    /// The prefix, without the separating underscore.
+    const PREFIX: &'static str;
+}
+
+/// Serializes a ``TypeIdSuffix`` as `"{P::PREFIX}_{suffix}"`.
+///
+/// # Errors
+///
+/// Returns a serialization error if `P::PREFIX` is not a valid `TypeID` prefix.
+pub fn serialize<P, S>(suffix: &TypeIdSuffix, serializer: S) -> Result<S::Ok, S::Error>
+where
+    P: Prefix,
+    S: Serializer,
+{
+    let typeid = suffix.to_typeid_string(P::PREFIX).map_err(S::Error::custom)?;
+    serializer.serialize_str(&typeid)
+}
+
+/// Deserializes a ``TypeIdSuffix`` from a `"{P::PREFIX}_{suffix}"` string.
+///
+/// The prefix must match `P::PREFIX` exactly and is discarded.
+///
+/// # Errors
+///
+/// Returns a deserialization error if the input is not a string, the prefix
+/// does not match, or the suffix fails `from_str`.
+pub fn deserialize<'de, P, D>(deserializer: D) -> Result<TypeIdSuffix, D::Error>
+where
+    P: Prefix,
+    D: Deserializer<'de>,
+{
+    let typeid = String::deserialize(deserializer)?;
+    let (prefix, suffix) = TypeIdSuffix::from_typeid_str(&typeid).map_err(D::Error::custom)?;
+    let prefix = prefix.unwrap_or_default();
+    if prefix != P::PREFIX {
+        return Err(D::Error::custom(format!(
+            "expected prefix `{}`, found `{prefix}`",
+            P::PREFIX
+        )));
+    }
+    Ok(suffix)
+}
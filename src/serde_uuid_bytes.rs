@@ -0,0 +1,57 @@
+//! Serde helpers for storing a ``TypeIdSuffix`` as its 16 UUID bytes.
+//!
+//! A ``TypeIdSuffix`` normally serializes as the 26-character suffix string,
+//! and its `Deserialize` impl only reads that string back from binary formats.
+//! Fields that hold, or once held, the raw 16-byte UUID can use these
+//! functions with `serialize_with`/`deserialize_with` instead. `deserialize`
+//! accepts both forms, so records written as strings and as bytes can be read
+//! into the same type:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use typeid_suffix::prelude::*;
+//! use typeid_suffix::serde_uuid_bytes;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Account {
+//!     #[serde(with = "serde_uuid_bytes")]
+//!     id: TypeIdSuffix,
+//! }
+//!
+//! let account = Account { id: TypeIdSuffix::new::<V7>() };
+//! let bytes = bincode::serialize(&account).unwrap();
+//! assert_eq!(bytes.len(), 8 + 16);
+//! assert_eq!(bincode::deserialize::<Account>(&bytes).unwrap().id, account.id);
+//! ```
+
+use serde::{Deserializer, Serializer};
+
+use crate::typeid_suffix::{TypeIdSuffix, TypeIdSuffixVisitor};
+
+/// Serializes a ``TypeIdSuffix`` as the 16 big-endian bytes of its UUID.
+///
+/// # Errors
+///
+/// Returns any error produced by the serializer.
+pub fn serialize<S: Serializer>(suffix: &TypeIdSuffix, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(suffix.to_uuid().as_bytes())
+}
+
+/// Deserializes a ``TypeIdSuffix`` from 16 UUID bytes or from its string representation.
+///
+/// Human-readable formats may hold a suffix string or an array of 16 bytes.
+/// Binary formats are read as a byte buffer, which accepts the 16 raw bytes of
+/// a UUID as well as a 26-byte encoded suffix (how strings are stored in
+/// formats such as `bincode`).
+///
+/// # Errors
+///
+/// Returns a deserialization error if a string fails `from_str`, or if the
+/// bytes are neither 16 nor 26 long or fail the same validation as `from_uuid`.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TypeIdSuffix, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(TypeIdSuffixVisitor)
+    } else {
+        deserializer.deserialize_bytes(TypeIdSuffixVisitor)
+    }
+}
@@ -0,0 +1,210 @@
+//! AVX2 implementations of the base32 encoder and decoder.
+//!
+//! `encoding::encode_base32` and `encoding::decode_base32` dispatch here when the
+//! `simd` feature is enabled and the CPU reports AVX2 support at runtime. Every
+//! other configuration uses the scalar loops, and both paths produce identical
+//! output and errors, including the overflow guard on the first character.
+//!
+//! Encoding gathers the two bytes that hold each 5-bit group with a byte shuffle,
+//! shifts every lane by its own offset, and maps the 26 indices to the alphabet
+//! with two 16-entry table lookups. Decoding maps all characters through nibble
+//! lookup tables in one pass and merges the 5-bit digits pairwise with
+//! multiply-add instructions, leaving only seven 20-bit chunks to combine.
+
+#![allow(unsafe_code)]
+#![allow(clippy::cast_possible_truncation)]
+
+use std::arch::x86_64::{
+    __m256i, _mm256_and_si256, _mm256_andnot_si256, _mm256_blendv_epi8, _mm256_broadcastsi128_si256,
+    _mm256_cmpeq_epi8, _mm256_cmpgt_epi8, _mm256_loadu_si256, _mm256_madd_epi16, _mm256_maddubs_epi16,
+    _mm256_movemask_epi8, _mm256_or_si256, _mm256_packus_epi16, _mm256_packus_epi32,
+    _mm256_permutevar8x32_epi32, _mm256_set1_epi16, _mm256_set1_epi32, _mm256_set1_epi8, _mm256_set_m128i,
+    _mm256_setr_epi32, _mm256_shuffle_epi8, _mm256_srli_epi16, _mm256_srlv_epi32, _mm256_storeu_si256,
+    _mm_loadu_si128, _mm_slli_si128, _mm_srli_si128,
+};
+
+use crate::encoding::{ALPHABET, DECODE_TABLE};
+use crate::errors::{DecodeError, InvalidSuffixReason};
+
+// Byte shuffles and bit shifts that place character `i` of the encoding in the low
+// bits of 32-bit lane `i % 8` of vector `i / 8`. Character `i` holds bits
+// `(25 - i) * 5 ..` of the value, counted from the least significant bit; the
+// shuffle loads the two little-endian bytes containing them, and the shift moves
+// them down. Lanes 26 to 31 are padding and select only zero bytes.
+const ENCODE_SHUFFLES: [[u8; 32]; 4] = {
+    let mut shuffles = [[0x80; 32]; 4];
+    let mut i = 0;
+    while i < 26 {
+        let bit = (25 - i) * 5;
+        let byte = bit / 8;
+        // The input is big-endian, so little-endian byte `n` is at index `15 - n`
+        shuffles[i / 8][(i % 8) * 4] = (15 - byte) as u8;
+        if byte < 15 {
+            shuffles[i / 8][(i % 8) * 4 + 1] = (14 - byte) as u8;
+        }
+        i += 1;
+    }
+    shuffles
+};
+
+// Each shift is below 8, so it fits in the low byte of its little-endian 32-bit lane
+const ENCODE_SHIFTS: [[u8; 32]; 4] = {
+    let mut shifts = [[0; 32]; 4];
+    let mut i = 0;
+    while i < 26 {
+        shifts[i / 8][(i % 8) * 4] = ((25 - i) * 5 % 8) as u8;
+        i += 1;
+    }
+    shifts
+};
+
+// `ALPHABET` split into halves, each repeated in both 128-bit lanes for `_mm256_shuffle_epi8`
+const ALPHABET_LOW: [u8; 32] = alphabet_half(0);
+const ALPHABET_HIGH: [u8; 32] = alphabet_half(16);
+
+// `DECODE_TABLE` rows for the only high nibbles that contain valid characters:
+// `0x3_` holds the digits, and `0x6_` and `0x7_` hold the lowercase letters
+const DECODE_ROW_3: [u8; 32] = decode_row(0x30);
+const DECODE_ROW_6: [u8; 32] = decode_row(0x60);
+const DECODE_ROW_7: [u8; 32] = decode_row(0x70);
+
+// `'0'` in the two leading and four trailing padding positions of the decoder input
+const DECODE_PADDING: [u8; 32] = {
+    let mut padding = [0; 32];
+    padding[0] = b'0';
+    padding[1] = b'0';
+    padding[28] = b'0';
+    padding[29] = b'0';
+    padding[30] = b'0';
+    padding[31] = b'0';
+    padding
+};
+
+const fn alphabet_half(start: usize) -> [u8; 32] {
+    let mut half = [0; 32];
+    let mut i = 0;
+    while i < 32 {
+        half[i] = ALPHABET[start + i % 16];
+        i += 1;
+    }
+    half
+}
+
+const fn decode_row(start: usize) -> [u8; 32] {
+    let mut row = [0; 32];
+    let mut i = 0;
+    while i < 32 {
+        row[i] = DECODE_TABLE[start + i % 16];
+        i += 1;
+    }
+    row
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn load(bytes: &[u8; 32]) -> __m256i {
+    // SAFETY: `bytes` is 32 readable bytes, and `loadu` has no alignment requirement
+    unsafe { _mm256_loadu_si256(bytes.as_ptr().cast()) }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+fn store(vector: __m256i) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    // SAFETY: `bytes` is 32 writable bytes, and `storeu` has no alignment requirement
+    unsafe { _mm256_storeu_si256(bytes.as_mut_ptr().cast(), vector) };
+    bytes
+}
+
+/// Encodes a 16-byte UUID into a 26-character base32 string.
+///
+/// Callers must have detected AVX2 support at runtime before calling this.
+#[target_feature(enable = "avx2")]
+pub fn encode_base32(uuid: &[u8; 16]) -> [u8; 26] {
+    // SAFETY: `uuid` is 16 readable bytes, and `loadu` has no alignment requirement
+    let input = _mm256_broadcastsi128_si256(unsafe { _mm_loadu_si128(uuid.as_ptr().cast()) });
+    let mask = _mm256_set1_epi32(0x1F);
+
+    let mut indices = [_mm256_set1_epi32(0); 4];
+    for (vector, (shuffle, shift)) in indices.iter_mut().zip(ENCODE_SHUFFLES.iter().zip(&ENCODE_SHIFTS)) {
+        let (shuffle, shift) = (load(shuffle), load(shift));
+        *vector = _mm256_and_si256(_mm256_srlv_epi32(_mm256_shuffle_epi8(input, shuffle), shift), mask);
+    }
+
+    // Narrow the 32-bit lanes to bytes. The packs interleave 128-bit lanes, leaving
+    // the 4-byte groups in the order 0, 2, 4, 6, 1, 3, 5, 7, which the permute undoes
+    let words_low = _mm256_packus_epi32(indices[0], indices[1]);
+    let words_high = _mm256_packus_epi32(indices[2], indices[3]);
+    let indices = _mm256_permutevar8x32_epi32(
+        _mm256_packus_epi16(words_low, words_high),
+        _mm256_setr_epi32(0, 4, 1, 5, 2, 6, 3, 7),
+    );
+
+    // Look up each index in both halves of the alphabet and keep the matching one
+    let low = _mm256_shuffle_epi8(load(&ALPHABET_LOW), indices);
+    let high = _mm256_shuffle_epi8(load(&ALPHABET_HIGH), indices);
+    let characters = _mm256_blendv_epi8(low, high, _mm256_cmpgt_epi8(indices, _mm256_set1_epi8(15)));
+
+    let characters = store(characters);
+    let mut encoded_output = [0; 26];
+    encoded_output.copy_from_slice(&characters[..26]);
+    encoded_output
+}
+
+/// Decodes a 26-character base32 string back into a 16-byte UUID.
+///
+/// Callers must have detected AVX2 support at runtime before calling this.
+#[target_feature(enable = "avx2")]
+pub fn decode_base32(encoded: &[u8; 26]) -> Result<[u8; 16], DecodeError> {
+    // Two leading zero digits round the input up to 28 digits, seven groups of four.
+    // The vector is assembled from two overlapping loads rather than copied through
+    // a buffer, because a wide load of narrower stores cannot be forwarded
+    // SAFETY: both loads read 16 bytes within `encoded`, and `loadu` has no alignment requirement
+    let (head, tail) = unsafe {
+        (_mm_loadu_si128(encoded.as_ptr().cast()), _mm_loadu_si128(encoded[10..].as_ptr().cast()))
+    };
+    let characters = _mm256_or_si256(
+        _mm256_set_m128i(_mm_srli_si128(tail, 4), _mm_slli_si128(head, 2)),
+        load(&DECODE_PADDING),
+    );
+
+    // Look up each character by its low nibble in the row for its high nibble
+    let nibble_mask = _mm256_set1_epi8(0x0F);
+    let low_nibbles = _mm256_and_si256(characters, nibble_mask);
+    let high_nibbles = _mm256_and_si256(_mm256_srli_epi16(characters, 4), nibble_mask);
+    let in_row_3 = _mm256_cmpeq_epi8(high_nibbles, _mm256_set1_epi8(0x3));
+    let in_row_6 = _mm256_cmpeq_epi8(high_nibbles, _mm256_set1_epi8(0x6));
+    let in_row_7 = _mm256_cmpeq_epi8(high_nibbles, _mm256_set1_epi8(0x7));
+    let digits = _mm256_or_si256(
+        _mm256_or_si256(
+            _mm256_and_si256(in_row_3, _mm256_shuffle_epi8(load(&DECODE_ROW_3), low_nibbles)),
+            _mm256_and_si256(in_row_6, _mm256_shuffle_epi8(load(&DECODE_ROW_6), low_nibbles)),
+        ),
+        _mm256_or_si256(
+            _mm256_and_si256(in_row_7, _mm256_shuffle_epi8(load(&DECODE_ROW_7), low_nibbles)),
+            // Characters in any other row are invalid
+            _mm256_andnot_si256(_mm256_or_si256(_mm256_or_si256(in_row_3, in_row_6), in_row_7), _mm256_set1_epi8(-1)),
+        ),
+    );
+
+    // The scalar loop reports an invalid character before checking for overflow,
+    // so the same order is kept here
+    if _mm256_movemask_epi8(_mm256_cmpeq_epi8(digits, _mm256_set1_epi8(-1))) != 0 {
+        return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidCharacter));
+    }
+    if DECODE_TABLE[encoded[0] as usize] > 7 {
+        return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter));
+    }
+
+    // Merge adjacent digits into 10-bit pairs, then adjacent pairs into 20-bit groups
+    let pairs = _mm256_maddubs_epi16(digits, _mm256_set1_epi16(0x0120));
+    let groups = _mm256_madd_epi16(pairs, _mm256_set1_epi32(0x0001_0400));
+
+    let groups = store(groups);
+    // The last group is padding. The first 12 of the 140 bits are zero once the
+    // first character has been checked, so the shifts discard only zero bits
+    let value = groups[..28]
+        .chunks_exact(4)
+        .fold(0u128, |value, group| (value << 20) | u128::from(u32::from_le_bytes([group[0], group[1], group[2], group[3]])));
+    Ok(value.to_be_bytes())
+}
@@ -0,0 +1,43 @@
+//! Validation rules for specific versions of the `TypeID` specification.
+//!
+//! `from_str` follows the current specification, which accepts any 128-bit
+//! value as a suffix. Data written against an earlier draft can be checked
+//! against that draft's stricter rules with `TypeIdSuffix::from_str_for_spec`.
+
+use uuid::{Uuid, Version};
+
+use crate::errors::InvalidUuidReason;
+use crate::typeid_suffix::TypeIdSuffix;
+
+/// A version of the `TypeID` specification to validate suffixes against.
+///
+/// | Version | Accepted suffixes |
+/// |---------|-------------------|
+/// | `V0_2`  | Only RFC4122-variant `UUIDv7` values, which the draft described as the suffix format. |
+/// | `V0_3`  | Any 128-bit value, including other UUID versions and variants. This matches `from_str`. |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SpecVersion {
+    /// Version 0.2 of the specification, which requires `UUIDv7` suffixes.
+    V0_2,
+    /// Version 0.3 of the specification, which accepts any 128-bit value.
+    #[default]
+    V0_3,
+}
+
+impl SpecVersion {
+    /// Checks a decoded UUID against the rules of this specification version.
+    pub(crate) fn check_uuid(self, uuid: &Uuid) -> Result<(), InvalidUuidReason> {
+        match self {
+            Self::V0_2 => {
+                TypeIdSuffix::check_strict_uuid(uuid)?;
+                if uuid.get_version() == Some(Version::SortRand) {
+                    Ok(())
+                } else {
+                    Err(InvalidUuidReason::InvalidVersion)
+                }
+            }
+            Self::V0_3 => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! `sqlx` support for ``TypeIdSuffix``.
+//!
+//! This module maps ``TypeIdSuffix`` onto the database's native text type, so a
+//! suffix can be bound as a query parameter and read back from a `TEXT` column.
+//! Values read from the database are validated with `from_str`.
+//!
+//! Each database is implemented behind its own feature (`sqlx-postgres` and
+//! `sqlx-sqlite`), so only the drivers you enable are compiled.
+
+use std::str::FromStr;
+
+use ::sqlx::decode::Decode;
+use ::sqlx::encode::{Encode, IsNull};
+use ::sqlx::error::BoxDynError;
+use ::sqlx::types::Type;
+use ::sqlx::Database;
+
+use crate::typeid_suffix::TypeIdSuffix;
+
+macro_rules! impl_sqlx_text {
+    ($db:ty) => {
+        impl Type<$db> for TypeIdSuffix {
+            fn type_info() -> <$db as Database>::TypeInfo {
+                <str as Type<$db>>::type_info()
+            }
+
+            fn compatible(ty: &<$db as Database>::TypeInfo) -> bool {
+                <str as Type<$db>>::compatible(ty)
+            }
+        }
+
+        impl<'q> Encode<'q, $db> for TypeIdSuffix {
+            /// Encodes the ``TypeIdSuffix`` as its 26-character string representation.
+            fn encode_by_ref(&self, buf: &mut <$db as Database>::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+                <String as Encode<'q, $db>>::encode(self.to_string(), buf)
+            }
+        }
+
+        impl<'r> Decode<'r, $db> for TypeIdSuffix {
+            /// Decodes a ``TypeIdSuffix`` from a text value.
+            ///
+            /// # Errors
+            ///
+            /// Returns the `DecodeError` produced by `from_str` if the stored value
+            /// is not a valid ``TypeIdSuffix``.
+            fn decode(value: <$db as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+                let s = <&'r str as Decode<'r, $db>>::decode(value)?;
+                Ok(Self::from_str(s)?)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl_sqlx_text!(::sqlx::Postgres);
+
+#[cfg(feature = "sqlx-sqlite")]
+impl_sqlx_text!(::sqlx::Sqlite);
@@ -0,0 +1,164 @@
+//! The `typed_id!` macro for declaring distinct ID types per entity.
+
+/// Declares a newtype over ``TypeIdSuffix`` with a fixed `TypeID` prefix.
+///
+/// `typed_id!(UserId, "user");` generates a `UserId` struct that:
+///
+/// - has a `PREFIX` constant (`"user"`) and a `new()` constructor using `UUIDv7`;
+/// - converts to and from ``TypeIdSuffix`` with `From`, and exposes it with `suffix()`;
+/// - implements `Display` as `user_01h455vb4pex5vsknk084sn02q`;
+/// - implements `FromStr`, rejecting input whose prefix is not `user`;
+/// - with the `serde` feature, serializes as the same `prefix_suffix` string.
+///
+/// Each generated type is distinct, so a `UserId` cannot be passed where an
+/// `OrderId` is expected. The prefix must pass `prefix::validate_prefix`
+/// (lowercase ASCII letters and underscores, at most 63 characters), which is
+/// checked at compile time. An empty prefix is allowed, and its IDs are written
+/// as the bare suffix, without a separator.
+///
+/// # Examples
+///
+/// ```
+/// use std::str::FromStr;
+/// use typeid_suffix::prelude::*;
+/// use typeid_suffix::typed_id;
+///
+/// typed_id!(UserId, "user");
+/// typed_id!(OrderId, "order");
+///
+/// let user = UserId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+/// assert_eq!(user.to_string(), "user_01h455vb4pex5vsknk084sn02q");
+/// assert!(UserId::from_str("order_01h455vb4pex5vsknk084sn02q").is_err());
+/// ```
+///
+/// Distinct typed IDs do not convert into each other:
+///
+/// ```compile_fail
+/// use typeid_suffix::typed_id;
+///
+/// typed_id!(UserId, "user");
+/// typed_id!(OrderId, "order");
+///
+/// let order: OrderId = UserId::new().into();
+/// ```
+///
+/// An invalid prefix fails the build:
+///
+/// ```compile_fail
+/// use typeid_suffix::typed_id;
+///
+/// typed_id!(UserId, "User");
+/// ```
+#[macro_export]
+macro_rules! typed_id {
+    ($(#[$meta:meta])* $vis:vis $name:ident, $prefix:literal) => {
+        const _: () = ::core::assert!(
+            $crate::prefix::validate_prefix($prefix).is_ok(),
+            ::core::concat!("invalid TypeID prefix for ", ::core::stringify!($name), ": ", $prefix)
+        );
+
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        $vis struct $name($crate::prelude::TypeIdSuffix);
+
+        impl $name {
+            /// The `TypeID` prefix of this ID type.
+            pub const PREFIX: &'static str = $prefix;
+
+            /// Creates a new ID from a `UUIDv7`.
+            #[must_use]
+            pub fn new() -> Self {
+                Self($crate::prelude::TypeIdSuffix::new::<$crate::prelude::V7>())
+            }
+
+            /// Returns the underlying ``TypeIdSuffix``.
+            #[must_use]
+            pub const fn suffix(&self) -> &$crate::prelude::TypeIdSuffix {
+                &self.0
+            }
+        }
+
+        impl ::core::default::Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl ::core::convert::From<$crate::prelude::TypeIdSuffix> for $name {
+            fn from(suffix: $crate::prelude::TypeIdSuffix) -> Self {
+                Self(suffix)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::prelude::TypeIdSuffix {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                if $prefix.is_empty() {
+                    write!(f, "{}", self.0)
+                } else {
+                    write!(f, "{}_{}", $prefix, self.0)
+                }
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::prelude::TypedIdError;
+
+            fn from_str(input: &str) -> ::core::result::Result<Self, Self::Err> {
+                // `from_typeid_str` rejects a separator without a prefix, so only a
+                // bare suffix matches an empty `PREFIX`
+                let (prefix, suffix) = $crate::prelude::TypeIdSuffix::from_typeid_str(input)?;
+                let prefix = prefix.unwrap_or_default();
+                if prefix != $prefix {
+                    return Err($crate::prelude::TypedIdError::PrefixMismatch {
+                        expected: $prefix,
+                        found: prefix.to_owned(),
+                    });
+                }
+                Ok(Self(suffix))
+            }
+        }
+
+        $crate::__typed_id_serde!($name);
+    };
+}
+
+/// Implements serde for a `typed_id!` type when the `serde` feature is enabled.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __typed_id_serde {
+    ($name:ident) => {
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let s = <::std::string::String as $crate::__private::serde::Deserialize>::deserialize(deserializer)?;
+                s.parse().map_err($crate::__private::serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+/// Implements serde for a `typed_id!` type when the `serde` feature is enabled.
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __typed_id_serde {
+    ($name:ident) => {};
+}
@@ -1,29 +1,57 @@
 //! This module implements the ``TypeIdSuffix`` struct and its associated functionality.
 //! ``TypeIdSuffix`` represents the suffix part of a `TypeId`, which is a base32-encoded UUID.
 
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
 use std::cmp::Ordering;
+use std::ffi::OsStr;
 use std::fmt;
-use std::ops::Deref;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::ops::{Bound, Deref, RangeInclusive};
 use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use uuid::{Uuid, Variant, Version};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::encoding::{decode_base32, encode_base32};
-use crate::errors::{DecodeError, InvalidSuffixReason, InvalidUuidReason};
-use crate::versions::{UuidVersion, V7};
+use crate::encoding::{decode, decode_base32, decode_base32_validated, encode, encode_base32};
+use crate::errors::{DecodeError, InvalidSuffixReason, InvalidUuidReason, PrefixError};
+use crate::prefix::validate_prefix;
+use crate::spec::SpecVersion;
+use crate::versions::UuidVersion;
 
 /// Represents a `TypeId` suffix, which is a 26-character base32-encoded UUID.
 ///
 /// This struct encapsulates the suffix part of a `TypeId`, providing methods for
 /// creation, conversion, and validation.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TypeIdSuffix([u8; 26]);
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(bytecheck(verify))
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+pub struct TypeIdSuffix(pub(crate) [u8; 26]);
 
 impl TypeIdSuffix {
+    /// The index of the base32 character that encodes the UUID version.
+    ///
+    /// The 26 characters encode 130 bits, the first two of which are always zero,
+    /// so the UUID version nibble (bits 48-51) lands in the character at index 10.
+    pub const VERSION_CHAR_INDEX: usize = 10;
+
+    /// The bit offset of the UUID version within its base32 character.
+    ///
+    /// The version occupies the high four bits of the character's 5-bit value,
+    /// so it is recovered as `(value >> VERSION_BIT_OFFSET) & 0xF`.
+    pub const VERSION_BIT_OFFSET: u32 = 1;
+
     /// Creates a new ``TypeIdSuffix`` from a specific UUID version.
     ///
     /// This method generates a new UUID of the specified version and encodes it
@@ -54,118 +82,1693 @@ impl TypeIdSuffix {
         Self(encode_base32(V::default().as_bytes()))
     }
 
-    /// Checks if a given UUID is valid according to the `TypeId` specification.
+    /// Creates a ``TypeIdSuffix`` from an existing UUID, validating it first.
     ///
-    /// This method validates both the variant and version of the UUID.
+    /// Unlike the infallible `From<Uuid>` conversion, this applies the same UUID
+    /// checks as `from_str`, so a suffix built here is always one that `from_str`
+    /// would accept. (The name `new` is taken by the generic generator
+    /// `new::<V>()`.)
     ///
     /// # Arguments
     ///
-    /// * `uuid`: A reference to the `Uuid` to be validated.
+    /// * `uuid`: The UUID to encode.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the new ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DecodeError::InvalidUuid` if the `strict-global` feature is
+    /// enabled and the UUID fails its checks. Without it, every UUID is accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let uuid = Uuid::now_v7();
+    /// let suffix = TypeIdSuffix::from_uuid(uuid).unwrap();
+    /// assert_eq!(suffix.to_uuid(), uuid);
+    /// ```
+    pub fn from_uuid(uuid: Uuid) -> Result<Self, DecodeError> {
+        #[cfg(feature = "strict-global")]
+        if let Err(reason) = Self::check_strict_uuid(&uuid) {
+            return Err(DecodeError::InvalidUuid(reason));
+        }
+        Ok(Self(encode_base32(uuid.as_bytes())))
+    }
+
+    /// Creates a new ``TypeIdSuffix`` from a specific UUID version, reporting generator failures.
+    ///
+    /// This is the fallible counterpart to `new`. UUID generation relies on the
+    /// system clock and random number generator, which some targets lack and
+    /// where the generator would panic. `try_new` generates through
+    /// `UuidVersion::try_generate`, which checks for them up front and returns
+    /// an error instead.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `V`: A type that implements `UuidVersion` and `Default`.
     ///
     /// # Returns
     ///
-    /// `true` if the UUID is valid, `false` otherwise.
-    const fn is_valid_uuid(uuid: &Uuid) -> bool {
-        let is_valid_variant = matches!(
-            uuid.get_variant(),
-            Variant::RFC4122 | Variant::Microsoft | Variant::Future | Variant::NCS
-        );
+    /// A `Result` containing either the new ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidUuidReason::GenerationUnavailable` if the UUID generator
+    /// for `V` is unavailable on the current target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::try_new::<V7>().unwrap();
+    /// assert_eq!(suffix.version(), Some(Version::SortRand));
+    /// ```
+    pub fn try_new<V>() -> Result<Self, DecodeError>
+    where
+        V: UuidVersion + Default,
+    {
+        let uuid = V::try_generate().map_err(DecodeError::InvalidUuid)?;
+        Ok(Self(encode_base32(uuid.as_bytes())))
+    }
+
+    /// Validates 26 encoded bytes and wraps them in a ``TypeIdSuffix``.
+    ///
+    /// This performs every check `from_str` performs after the length check.
+    /// It is a `const fn` so that `from_array` and `from_str_const` can share it,
+    /// and so it always uses the scalar decoder.
+    const fn from_encoded(encoded_bytes: [u8; 26]) -> Result<Self, DecodeError> {
+        match Self::validate_decoded(&encoded_bytes, decode::<16, 26>(&encoded_bytes)) {
+            Ok(_) => Ok(Self(encoded_bytes)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Validates 26 encoded bytes and returns the UUID they decode to.
+    ///
+    /// This is the runtime counterpart of `from_encoded`, which can use the
+    /// `simd` decoder, and lets `parse_with_uuid` avoid decoding a second time.
+    fn decode_encoded(encoded_bytes: &[u8; 26]) -> Result<Uuid, DecodeError> {
+        Self::validate_decoded(encoded_bytes, decode_base32(encoded_bytes))
+    }
+
+    /// Applies the `TypeId` validation rules to encoded bytes and the result of decoding them.
+    ///
+    /// The encoded bytes are checked before the decoding result so that errors
+    /// are reported in the same order regardless of which decoder was used.
+    const fn validate_decoded(
+        encoded_bytes: &[u8; 26],
+        decoded: Result<[u8; 16], DecodeError>,
+    ) -> Result<Uuid, DecodeError> {
+        if !encoded_bytes.is_ascii() {
+            return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter));
+        }
+        if encoded_bytes[0] > b'7' {
+            return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter));
+        }
+        let decoded_bytes = match decoded {
+            Ok(decoded_bytes) => decoded_bytes,
+            Err(e) => return Err(e),
+        };
+        // The `TypeId` specification accepts every 128-bit value, so the UUID
+        // itself is only checked under `strict-global`
+        let uuid = Uuid::from_bytes(decoded_bytes);
+        #[cfg(feature = "strict-global")]
+        if let Err(reason) = Self::check_strict_uuid(&uuid) {
+            return Err(DecodeError::InvalidUuid(reason));
+        }
+        Ok(uuid)
+    }
 
-        let is_valid_version = matches!(
+    /// Checks that a UUID has the RFC4122 variant and a version from 1 to 8.
+    ///
+    /// This is the validation applied by the `strict-global` feature and by
+    /// `TypeIdSuffixBuilder` in strict mode.
+    pub(crate) const fn check_strict_uuid(uuid: &Uuid) -> Result<(), InvalidUuidReason> {
+        if !matches!(uuid.get_variant(), Variant::RFC4122) {
+            return Err(InvalidUuidReason::InvalidVariant);
+        }
+        if !matches!(
             uuid.get_version(),
             Some(
-                Version::Max
-                    | Version::Custom
-                    | Version::SortMac
-                    | Version::Mac
+                Version::Mac
                     | Version::Dce
                     | Version::Md5
                     | Version::Random
                     | Version::Sha1
+                    | Version::SortMac
                     | Version::SortRand
-                    | Version::Nil
+                    | Version::Custom
             )
-        );
+        ) {
+            return Err(InvalidUuidReason::InvalidVersion);
+        }
+        Ok(())
+    }
+
+    /// Returns the length of the ``TypeIdSuffix`` string, which is always 26.
+    ///
+    /// This shadows `str::len` through `Deref`, so the length is known without
+    /// going through the string view and can be used in `const` contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// const SUFFIX: TypeIdSuffix = TypeIdSuffix::from_str_const("01h455vb4pex5vsknk084sn02q");
+    /// const LEN: usize = SUFFIX.len();
+    /// assert_eq!(LEN, 26);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        26
+    }
+
+    /// Returns `false`, since a ``TypeIdSuffix`` is never empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// assert!(!TypeIdSuffix::default().is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns a copy of the 26 encoded bytes of the ``TypeIdSuffix``.
+    ///
+    /// This is useful for fixed-width binary layouts that store the encoded
+    /// form without going through `String`.
+    ///
+    /// # Returns
+    ///
+    /// The 26 ASCII bytes of the base32-encoded suffix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// assert_eq!(&suffix.to_array(), suffix.as_bytes());
+    /// ```
+    #[must_use]
+    pub const fn to_array(&self) -> [u8; 26] {
+        self.0
+    }
+
+    /// Creates a ``TypeIdSuffix`` from 26 encoded bytes.
+    ///
+    /// The bytes are validated the same way `from_str` validates a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes`: The 26 ASCII bytes of a base32-encoded suffix.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The array contains non-ASCII bytes.
+    /// - The first byte is greater than `'7'`.
+    /// - The array contains bytes that are not in the base32 alphabet.
+    /// - The decoded UUID is not valid according to the `TypeId` specification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// assert_eq!(TypeIdSuffix::from_array(suffix.to_array()), Ok(suffix));
+    /// ```
+    pub const fn from_array(bytes: [u8; 26]) -> Result<Self, DecodeError> {
+        Self::from_encoded(bytes)
+    }
+
+    /// Creates a ``TypeIdSuffix`` from 26 encoded bytes without validating them.
+    ///
+    /// This is the fast path for bytes that have already been validated, such as
+    /// values read back from a trusted store. Use `from_array` for anything else.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes`: The 26 ASCII bytes of a base32-encoded suffix.
+    ///
+    /// # Returns
+    ///
+    /// The ``TypeIdSuffix`` wrapping `bytes` unchanged.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be a suffix that `from_array` would accept: every byte must be
+    /// in the lowercase base32 alphabet, the first byte must be `'7'` or less, and
+    /// the decoded UUID must pass the validation `from_str` applies. Other code,
+    /// such as the `String` conversions, relies on the bytes being ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// // SAFETY: the bytes come from a valid suffix
+    /// let copy = unsafe { TypeIdSuffix::from_array_unchecked(suffix.to_array()) };
+    /// assert_eq!(copy, suffix);
+    /// ```
+    #[allow(unsafe_code)]
+    #[must_use]
+    pub const unsafe fn from_array_unchecked(bytes: [u8; 26]) -> Self {
+        Self(bytes)
+    }
+
+    /// Finds the first valid ``TypeIdSuffix`` embedded in free-form text.
+    ///
+    /// Only 26-character windows that form a whole word are tried: the window
+    /// must start at the beginning of `haystack` or after a byte that is not an
+    /// ASCII letter or digit (such as the `_` separating a `TypeID` prefix), and
+    /// must be followed by the end of `haystack` or another such byte. Each
+    /// position is tried at most once, so the scan is linear in the input length.
+    ///
+    /// # Arguments
+    ///
+    /// * `haystack`: The text to search.
+    ///
+    /// # Returns
+    ///
+    /// The byte offset of the first match and the parsed ``TypeIdSuffix``, or
+    /// `None` if no valid suffix is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let (offset, suffix) = TypeIdSuffix::find_in("request id=user_01h455vb4pex5vsknk084sn02q done").unwrap();
+    /// assert_eq!(offset, 16);
+    /// assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+    /// ```
+    #[must_use]
+    pub fn find_in(haystack: &str) -> Option<(usize, Self)> {
+        let bytes = haystack.as_bytes();
+        let is_separator = |byte: &u8| !byte.is_ascii_alphanumeric();
+        (0..=bytes.len().checked_sub(26)?)
+            .filter(|&start| start == 0 || is_separator(&bytes[start - 1]))
+            .filter(|&start| bytes.get(start + 26).is_none_or(is_separator))
+            .find_map(|start| Self::from_buffer_at(bytes, start).ok().map(|suffix| (start, suffix)))
+    }
+
+    /// Parses a ``TypeIdSuffix`` from 26 bytes starting at `offset` within `buf`.
+    ///
+    /// This is useful for fixed-layout binary records where the ASCII suffix
+    /// sits at a known position, avoiding slicing at the call site.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf`: The buffer containing the encoded suffix.
+    /// * `offset`: The index of the first byte of the suffix within `buf`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the parsed ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidSuffixReason::InvalidLength` if fewer than 26 bytes are
+    /// available at `offset`, and otherwise the same errors as `from_str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let record = b"id:01h455vb4pex5vsknk084sn02q;";
+    /// let suffix = TypeIdSuffix::from_buffer_at(record, 3).unwrap();
+    /// assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+    /// ```
+    pub fn from_buffer_at(buf: &[u8], offset: usize) -> Result<Self, DecodeError> {
+        let encoded_bytes: [u8; 26] = offset
+            .checked_add(26)
+            .and_then(|end| buf.get(offset..end))
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))?;
+        Self::decode_encoded(&encoded_bytes).map(|_| Self(encoded_bytes))
+    }
+
+    /// Converts the `TypeIdSuffix` to a UUID.
+    ///
+    /// This method decodes the base32-encoded suffix back into a UUID.
+    ///
+    /// # Returns
+    ///
+    /// The `Uuid` represented by this `TypeIdSuffix`.
+    ///
+    /// This never panics. Every ``TypeIdSuffix`` holds a validated encoding, so
+    /// the bytes are decoded without re-checking them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::new::<V4>();
+    /// let uuid = suffix.to_uuid();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_uuid(&self) -> Uuid {
+        Uuid::from_bytes(decode_base32_validated(&self.0))
+    }
+
+    /// Returns the decoded UUID in its canonical hyphenated form.
+    ///
+    /// # Returns
+    ///
+    /// A `String` such as `01890a5d-ac96-774b-bcce-b302099a8057`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(suffix.to_uuid_string(), "01890a5d-ac96-774b-bcce-b302099a8057");
+    /// ```
+    #[must_use]
+    pub fn to_uuid_string(&self) -> String {
+        self.to_uuid().hyphenated().to_string()
+    }
+
+    /// Returns the decoded UUID as 32 hexadecimal digits without hyphens.
+    ///
+    /// # Returns
+    ///
+    /// A `String` such as `01890a5dac96774bbcceb302099a8057`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(suffix.to_uuid_simple_string(), "01890a5dac96774bbcceb302099a8057");
+    /// ```
+    #[must_use]
+    pub fn to_uuid_simple_string(&self) -> String {
+        self.to_uuid().simple().to_string()
+    }
+
+    /// Converts the ``TypeIdSuffix`` to a UUID, checking that it has the expected version.
+    ///
+    /// This makes a version expectation explicit at the decode boundary, instead
+    /// of silently accepting a UUID of another version.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `V`: The expected UUID version, which must implement the `UuidVersion` trait.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the decoded `Uuid` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidUuidReason::InvalidVersion` if `V::validate` rejects the UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::new::<V7>();
+    /// assert_eq!(suffix.to_uuid_as::<V7>(), Ok(suffix.to_uuid()));
+    /// assert_eq!(
+    ///     suffix.to_uuid_as::<V4>(),
+    ///     Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVersion))
+    /// );
+    /// ```
+    pub fn to_uuid_as<V: UuidVersion>(&self) -> Result<Uuid, DecodeError> {
+        let uuid = self.to_uuid();
+        V::validate(&uuid).map_err(DecodeError::InvalidUuid)?;
+        Ok(uuid)
+    }
 
-        is_valid_variant || is_valid_version
+    /// Returns the decoded UUID as a big-endian 128-bit integer.
+    ///
+    /// # Returns
+    ///
+    /// The same value as `to_uuid().as_u128()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from(Uuid::max());
+    /// assert_eq!(suffix.to_u128(), u128::MAX);
+    /// ```
+    #[must_use]
+    pub fn to_u128(&self) -> u128 {
+        self.to_uuid().as_u128()
+    }
+
+    /// Returns the decoded UUID as high and low 64-bit halves.
+    ///
+    /// This suits FFI boundaries and languages without native 128-bit integers.
+    ///
+    /// # Returns
+    ///
+    /// A `(high, low)` tuple, the same value as `to_uuid().as_u64_pair()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from(Uuid::from_u128(1 << 64 | 2));
+    /// assert_eq!(suffix.to_u64_pair(), (1, 2));
+    /// ```
+    #[must_use]
+    pub fn to_u64_pair(&self) -> (u64, u64) {
+        self.to_uuid().as_u64_pair()
+    }
+
+    /// Creates a ``TypeIdSuffix`` from the high and low 64-bit halves of a UUID.
+    ///
+    /// Like `From<Uuid>`, this encodes the value without validating the UUID.
+    ///
+    /// # Arguments
+    ///
+    /// * `high`: The most significant 64 bits.
+    /// * `low`: The least significant 64 bits.
+    ///
+    /// # Returns
+    ///
+    /// The ``TypeIdSuffix`` encoding `(high << 64) | low`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// let (high, low) = suffix.to_u64_pair();
+    /// assert_eq!(TypeIdSuffix::from_u64_pair(high, low), suffix);
+    /// ```
+    #[must_use]
+    pub fn from_u64_pair(high: u64, low: u64) -> Self {
+        Self::from(Uuid::from_u64_pair(high, low))
+    }
+
+    /// Returns the decoded UUID bytes for a protobuf or Cap'n Proto `bytes` field.
+    ///
+    /// This is the same value as `to_uuid().into_bytes()`, named for schemas that
+    /// model the identifier as a 16-byte field. `from_proto_bytes` reverses it.
+    ///
+    /// # Returns
+    ///
+    /// The 16 big-endian bytes of the decoded UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// assert_eq!(TypeIdSuffix::from_proto_bytes(&suffix.to_proto_bytes()), Ok(suffix));
+    /// ```
+    #[must_use]
+    pub fn to_proto_bytes(&self) -> [u8; 16] {
+        self.to_uuid().into_bytes()
+    }
+
+    /// Creates a ``TypeIdSuffix`` from a protobuf or Cap'n Proto `bytes` field.
+    ///
+    /// Generated message types expose `bytes` fields as slices of any length, so
+    /// the length is checked here before the UUID is validated and encoded as by
+    /// `from_uuid`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes`: The field contents, which must be exactly 16 bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the new ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidUuidReason::InvalidBytes` if `bytes` is not 16 bytes long,
+    /// or the error from `from_uuid` if the UUID fails validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let uuid = Uuid::now_v7();
+    /// assert_eq!(TypeIdSuffix::from_proto_bytes(uuid.as_bytes()).unwrap().to_uuid(), uuid);
+    /// assert_eq!(
+    ///     TypeIdSuffix::from_proto_bytes(&[0; 15]),
+    ///     Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidBytes))
+    /// );
+    /// ```
+    pub fn from_proto_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let uuid = Uuid::from_slice(bytes).map_err(|_| DecodeError::InvalidUuid(InvalidUuidReason::InvalidBytes))?;
+        Self::from_uuid(uuid)
+    }
+
+    /// Returns the smallest ``TypeIdSuffix`` strictly greater than this one.
+    ///
+    /// The suffix is treated as a 128-bit integer and incremented by one. This is
+    /// useful for building exclusive lower bounds in range scans. As with
+    /// `From<Uuid>`, the result is not checked against the `strict-global` rules.
+    ///
+    /// # Returns
+    ///
+    /// `Some` containing the next ``TypeIdSuffix``, or `None` if this is the
+    /// maximum suffix (`7zzzzzzzzzzzzzzzzzzzzzzzzz`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from(Uuid::nil());
+    /// assert_eq!(suffix.checked_increment().unwrap().to_string(), "00000000000000000000000001");
+    /// assert_eq!(TypeIdSuffix::from(Uuid::max()).checked_increment(), None);
+    /// ```
+    #[must_use]
+    pub fn checked_increment(&self) -> Option<Self> {
+        self.to_u128().checked_add(1).map(|value| Self::from(Uuid::from_u128(value)))
+    }
+
+    /// Returns the version of the UUID encoded in the ``TypeIdSuffix``.
+    ///
+    /// This decodes the suffix and delegates to `Uuid::get_version`.
+    ///
+    /// # Returns
+    ///
+    /// The UUID `Version`, or `None` if the version is not recognized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::new::<V4>();
+    /// assert_eq!(suffix.version(), Some(Version::Random));
+    /// ```
+    #[must_use]
+    pub fn version(&self) -> Option<Version> {
+        self.to_uuid().get_version()
+    }
+
+    /// Returns the variant of the UUID encoded in the ``TypeIdSuffix``.
+    ///
+    /// This decodes the suffix and delegates to `Uuid::get_variant`.
+    ///
+    /// # Returns
+    ///
+    /// The UUID `Variant`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    /// use uuid::Variant;
+    ///
+    /// let suffix = TypeIdSuffix::new::<V4>();
+    /// assert_eq!(suffix.variant(), Variant::RFC4122);
+    /// ```
+    #[must_use]
+    pub fn variant(&self) -> Variant {
+        self.to_uuid().get_variant()
+    }
+
+    /// Returns a string slice of the ``TypeIdSuffix``.
+    ///
+    /// This method provides a way to access the underlying string representation
+    /// of the ``TypeIdSuffix``.
+    ///
+    /// # Returns
+    ///
+    /// A string slice containing the base32-encoded ``TypeIdSuffix``.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::new::<V4>();
+    /// let suffix_str = suffix.as_ref();
+    /// assert_eq!(suffix_str.len(), 26);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[allow(unsafe_code)]
+    fn as_str(&self) -> &str {
+        debug_assert!(self.0.is_ascii(), "TypeIdSuffix bytes must be ASCII");
+        // SAFETY: The internal bytes are always ASCII. Safe constructors only produce
+        // base32 alphabet bytes, `from_array_unchecked` requires ASCII of its caller,
+        // and the only mutation, `Zeroize`, writes NUL bytes, which are also ASCII.
+        // ASCII is valid UTF-8, so re-checking on every call is wasted work
+        unsafe { std::str::from_utf8_unchecked(&self.0) }
+    }
+
+    /// Returns the ``TypeIdSuffix`` as a borrowed `Cow<str>`.
+    ///
+    /// This lets APIs that accept `Cow<str>` receive a suffix without allocating.
+    ///
+    /// # Returns
+    ///
+    /// `Cow::Borrowed` containing the 26-character suffix string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// assert!(matches!(suffix.as_cow(), Cow::Borrowed(s) if s == suffix.to_string()));
+    /// ```
+    #[must_use]
+    pub fn as_cow(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.as_str())
+    }
+
+    /// Writes the ``TypeIdSuffix`` to an `io::Write` as its 26 raw ASCII bytes.
+    ///
+    /// Unlike `write!(w, "{suffix}")`, this skips the `fmt` machinery and hands
+    /// the bytes to the writer in a single `write_all` call.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer`: The writer to write the suffix to.
+    ///
+    /// # Errors
+    ///
+    /// Returns any `io::Error` produced by the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// let mut buffer = Vec::new();
+    /// suffix.write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, suffix.to_string().as_bytes());
+    /// ```
+    pub fn write_to<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.0)
+    }
+
+    /// Writes the ``TypeIdSuffix`` to any `fmt::Write`, such as a fixed-capacity string.
+    ///
+    /// This appends the 26-character suffix with a single `write_str` call, so a
+    /// log line can be assembled in a stack buffer without allocating.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer`: The writer to append the suffix to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `fmt::Error` if the writer rejects the string, for example when a
+    /// fixed-capacity buffer is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// let mut line = String::from("id=");
+    /// suffix.fmt_into(&mut line).unwrap();
+    /// assert_eq!(line, format!("id={suffix}"));
+    /// ```
+    pub fn fmt_into<W: fmt::Write>(&self, writer: &mut W) -> fmt::Result {
+        writer.write_str(self.as_str())
+    }
+
+    /// Computes a stable 24-bit value suitable for color-coding the ``TypeIdSuffix``.
+    ///
+    /// The value is derived from the 16 decoded UUID bytes by taking their
+    /// 32-bit FNV-1a hash (offset basis `0x811c9dc5`, prime `0x01000193`) and
+    /// XOR-folding the top 8 bits into the lower 24 bits. This derivation is
+    /// part of the public contract and will not change between releases, so the
+    /// same suffix always renders with the same color.
+    ///
+    /// # Returns
+    ///
+    /// A `u32` in the range `0..=0xFF_FFFF`, usable as an `0xRRGGBB` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// let color = suffix.color_hash();
+    /// assert!(color <= 0xFF_FFFF);
+    /// assert_eq!(color, suffix.color_hash());
+    /// ```
+    #[must_use]
+    pub fn color_hash(&self) -> u32 {
+        let hash = self
+            .to_uuid()
+            .as_bytes()
+            .iter()
+            .fold(0x811c_9dc5_u32, |hash, &byte| (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193));
+        (hash >> 24) ^ (hash & 0x00FF_FFFF)
+    }
+
+    /// Returns the Unix millisecond timestamp embedded in a `UUIDv7` ``TypeIdSuffix``.
+    ///
+    /// # Returns
+    ///
+    /// The 48-bit millisecond field, or `None` if the suffix is not a `UUIDv7`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let uuid = uuid::Builder::from_unix_timestamp_millis(1_700_000_000_000, &[0; 10]).into_uuid();
+    /// assert_eq!(TypeIdSuffix::from(uuid).timestamp_millis(), Some(1_700_000_000_000));
+    /// assert_eq!(TypeIdSuffix::new::<V4>().timestamp_millis(), None);
+    /// ```
+    #[must_use]
+    pub fn timestamp_millis(&self) -> Option<u64> {
+        let uuid = self.to_uuid();
+        match uuid.get_version()? {
+            Version::SortRand => Some((uuid.as_u128() >> 80) as u64),
+            _ => None,
+        }
+    }
+
+    /// Returns the creation time embedded in a `UUIDv6` or `UUIDv7` ``TypeIdSuffix``.
+    ///
+    /// For `UUIDv7` this is the 48-bit Unix millisecond field. For `UUIDv6` the
+    /// 60-bit count of 100-nanosecond intervals since the Gregorian epoch is
+    /// reassembled from its split `time_high`/`time_mid`/`time_low` fields and
+    /// converted to the Unix epoch.
+    ///
+    /// # Returns
+    ///
+    /// The embedded `SystemTime`, or `None` for other UUID versions or for a
+    /// `UUIDv6` timestamp that predates the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::new::<V7>();
+    /// let elapsed = SystemTime::now().duration_since(suffix.timestamp().unwrap()).unwrap();
+    /// assert!(elapsed < Duration::from_secs(1));
+    ///
+    /// assert_eq!(TypeIdSuffix::new::<V4>().timestamp(), None);
+    /// ```
+    #[must_use]
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        // 100-nanosecond intervals between 1582-10-15 and 1970-01-01
+        const GREGORIAN_OFFSET: u64 = 0x01B2_1DD2_1381_4000;
+
+        let value = self.to_uuid().as_u128();
+        match self.version()? {
+            Version::SortRand => {
+                let millis = (value >> 80) as u64;
+                Some(UNIX_EPOCH + Duration::from_millis(millis))
+            }
+            Version::SortMac => {
+                // time_high (32 bits) | time_mid (16 bits) | version (4 bits) | time_low (12 bits)
+                let high = (value >> 96) as u64;
+                let mid = ((value >> 80) & 0xFFFF) as u64;
+                let low = ((value >> 64) & 0x0FFF) as u64;
+                let ticks = (high << 28) | (mid << 12) | low;
+                let since_unix = ticks.checked_sub(GREGORIAN_OFFSET)?;
+                // Split into seconds first, as the full count in nanoseconds can overflow a u64
+                let since_unix = Duration::from_secs(since_unix / 10_000_000)
+                    + Duration::from_nanos((since_unix % 10_000_000) * 100);
+                Some(UNIX_EPOCH + since_unix)
+            }
+            _ => None,
+        }
+    }
+
+    /// Builds the inclusive range of `UUIDv7` suffixes created within a millisecond window.
+    ///
+    /// The lower bound is the `UUIDv7` with timestamp `start_ms` and every random
+    /// bit cleared; the upper bound has timestamp `end_ms` and every random bit set.
+    /// Because `Ord` follows the UUID byte order, any `UUIDv7` suffix whose
+    /// timestamp falls within `start_ms..=end_ms` is contained in the range.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_ms`: The first millisecond of the window, since the Unix epoch.
+    /// * `end_ms`: The last millisecond of the window, since the Unix epoch.
+    ///
+    /// # Returns
+    ///
+    /// A `RangeInclusive` spanning the minimal and maximal suffixes of the window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start_ms` is greater than `end_ms`, or if `end_ms` does not fit
+    /// in the 48-bit `UUIDv7` timestamp field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let range = TypeIdSuffix::range_for_millis(1_700_000_000_000, 1_700_000_000_999);
+    /// let inside: TypeIdSuffix = uuid::Builder::from_unix_timestamp_millis(1_700_000_000_500, &[7; 10])
+    ///     .into_uuid()
+    ///     .into();
+    /// assert!(range.contains(&inside));
+    /// ```
+    #[must_use]
+    pub fn range_for_millis(start_ms: u64, end_ms: u64) -> RangeInclusive<Self> {
+        assert!(start_ms <= end_ms, "start_ms must not be greater than end_ms");
+        assert!(end_ms < 1 << 48, "end_ms must fit in the 48-bit UUIDv7 timestamp");
+        let start = uuid::Builder::from_unix_timestamp_millis(start_ms, &[0x00; 10]).into_uuid();
+        let end = uuid::Builder::from_unix_timestamp_millis(end_ms, &[0xFF; 10]).into_uuid();
+        Self::from(start)..=Self::from(end)
+    }
+
+    /// Builds `BTreeMap` range bounds covering the `UUIDv7` suffixes created within a millisecond window.
+    ///
+    /// The bounds are the inclusive ends of `range_for_millis`, in the form
+    /// `BTreeMap::range` and `BTreeSet::range` accept.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_ms`: The first millisecond of the window, since the Unix epoch.
+    /// * `end_ms`: The last millisecond of the window, since the Unix epoch.
+    ///
+    /// # Returns
+    ///
+    /// A pair of `Bound::Included` bounds on the minimal and maximal suffixes of the window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start_ms` is greater than `end_ms`, or if `end_ms` does not fit
+    /// in the 48-bit `UUIDv7` timestamp field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let at = |ms| TypeIdSuffix::from(uuid::Builder::from_unix_timestamp_millis(ms, &[7; 10]).into_uuid());
+    /// let map = BTreeMap::from([(at(1_000), "early"), (at(2_000), "inside"), (at(3_000), "late")]);
+    ///
+    /// let found: Vec<_> = map.range(TypeIdSuffix::time_range_bounds(1_500, 2_500)).map(|(_, v)| *v).collect();
+    /// assert_eq!(found, ["inside"]);
+    /// ```
+    #[must_use]
+    pub fn time_range_bounds(start_ms: u64, end_ms: u64) -> (Bound<Self>, Bound<Self>) {
+        let (start, end) = Self::range_for_millis(start_ms, end_ms).into_inner();
+        (Bound::Included(start), Bound::Included(end))
+    }
+
+    /// Derives a deterministic ``TypeIdSuffix`` from two parent suffixes.
+    ///
+    /// The two decoded UUIDs are ordered, then hashed with SHA-1 (the `UUIDv5`
+    /// construction, using the smaller UUID as the namespace and the larger one
+    /// as the name). The hash is tagged as a `UUIDv4`, so the result has the
+    /// same shape as a random suffix. Because the inputs are ordered first,
+    /// `a.combine(&b) == b.combine(&a)`, and the same pair always yields the
+    /// same result, making it suitable as a composite key for join tables.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The other parent ``TypeIdSuffix``.
+    ///
+    /// # Returns
+    ///
+    /// A new `UUIDv4`-tagged ``TypeIdSuffix`` derived from both parents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let user = TypeIdSuffix::default();
+    /// let group = TypeIdSuffix::default();
+    ///
+    /// let membership = user.combine(&group);
+    /// assert_eq!(membership, group.combine(&user));
+    /// assert_eq!(membership.version(), Some(Version::Random));
+    /// ```
+    #[must_use]
+    pub fn combine(&self, other: &Self) -> Self {
+        let (low, high) = if self <= other { (self, other) } else { (other, self) };
+        let hashed = Uuid::new_v5(&low.to_uuid(), high.to_uuid().as_bytes());
+        uuid::Builder::from_random_bytes(hashed.into_bytes()).into_uuid().into()
+    }
+
+    /// Parses a string slice into a ``TypeIdSuffix`` using lenient Crockford rules.
+    ///
+    /// The input is lowercased and the ambiguous characters `i` and `l` are mapped
+    /// to `1` and `o` to `0` before the normal `from_str` validation runs. The
+    /// resulting ``TypeIdSuffix`` is always in canonical lowercase form.
+    ///
+    /// Lenient parsing is intended for user-supplied input (e.g. IDs that were
+    /// uppercased or autocorrected). Because it silently rewrites characters, it
+    /// may mask typos; use `from_str` where strict `TypeID` compliance is required.
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: The string slice to parse.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the parsed ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_str` for the normalized input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from_str_lenient("01H455VB4PEX5VSKNK084SN02Q").unwrap();
+    /// assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+    /// ```
+    pub fn from_str_lenient(input: &str) -> Result<Self, DecodeError> {
+        let normalized: String = input
+            .chars()
+            .map(|c| match c.to_ascii_lowercase() {
+                'i' | 'l' => '1',
+                'o' => '0',
+                c => c,
+            })
+            .collect();
+        Self::from_str(&normalized)
+    }
+
+    /// Compares the ``TypeIdSuffix`` against a string, ignoring ASCII case.
+    ///
+    /// This is useful for matching canonical suffixes against user-typed,
+    /// mixed-case input without normalizing it first.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The string slice to compare against.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `other` matches this suffix when case is ignored, `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert!(suffix.eq_ignore_case("01H455VB4PEX5VSKNK084SN02Q"));
+    /// ```
+    #[must_use]
+    pub fn eq_ignore_case(&self, other: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+
+    /// Renders the ``TypeIdSuffix`` in uppercase for display surfaces that require it.
+    ///
+    /// This is a presentation helper only: equality and storage remain
+    /// canonical lowercase, and so does `Display` unless the caller opts in to
+    /// uppercase with the alternate flag (`{:#}`), which avoids allocating. The
+    /// uppercase form is not accepted by `from_str`, but parses back to the same
+    /// suffix via `from_str_lenient`.
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the 26 uppercase base32 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// let upper = suffix.to_uppercase_string();
+    /// assert_eq!(upper, "01H455VB4PEX5VSKNK084SN02Q");
+    /// assert_eq!(TypeIdSuffix::from_str_lenient(&upper), Ok(suffix));
+    /// ```
+    #[must_use]
+    pub fn to_uppercase_string(&self) -> String {
+        self.as_str().to_ascii_uppercase()
+    }
+
+    /// Returns a fixed-width binary key that sorts in the same order as the ``TypeIdSuffix``.
+    ///
+    /// The key is the decoded UUID in big-endian byte order. The base32 alphabet
+    /// is in ASCII order and every suffix has the same length, so comparing keys
+    /// bytewise gives the same result as comparing the suffixes, which makes the
+    /// key suitable for indexing in databases and LSM stores.
+    ///
+    /// # Returns
+    ///
+    /// The 16 big-endian bytes of the decoded UUID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let low = TypeIdSuffix::from(Uuid::from_u128(1));
+    /// let high = TypeIdSuffix::from(Uuid::from_u128(1 << 64));
+    /// assert!(low < high);
+    /// assert!(low.sort_key() < high.sort_key());
+    /// ```
+    #[must_use]
+    pub fn sort_key(&self) -> [u8; 16] {
+        self.to_uuid().into_bytes()
+    }
+
+    /// Compares two ``TypeIdSuffix`` values by their raw encoded bytes.
+    ///
+    /// This is always the same as comparing the two suffix strings, independent
+    /// of UUID version or any other semantics. It currently agrees with `Ord`,
+    /// but names the guarantee for callers that previously sorted by string and
+    /// must keep doing so.
+    ///
+    /// # Arguments
+    ///
+    /// * `other`: The ``TypeIdSuffix`` to compare against.
+    ///
+    /// # Returns
+    ///
+    /// The lexicographic `Ordering` of the two 26-byte encodings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let low = TypeIdSuffix::from(Uuid::from_u128(1));
+    /// let high = TypeIdSuffix::from(Uuid::from_u128(2));
+    /// assert_eq!(low.cmp_by_bytes(&high), Ordering::Less);
+    /// ```
+    #[must_use]
+    pub fn cmp_by_bytes(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+
+    /// Returns an OpenTelemetry trace ID derived from the ``TypeIdSuffix``.
+    ///
+    /// OpenTelemetry trace IDs are 128 bits wide, so this is the full decoded
+    /// UUID in big-endian byte order.
+    ///
+    /// # Returns
+    ///
+    /// The 16 decoded UUID bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// assert_eq!(&suffix.to_otel_trace_id(), suffix.to_uuid().as_bytes());
+    /// ```
+    #[must_use]
+    pub fn to_otel_trace_id(&self) -> [u8; 16] {
+        self.to_uuid().into_bytes()
+    }
+
+    /// Returns an OpenTelemetry span ID derived from the ``TypeIdSuffix``.
+    ///
+    /// OpenTelemetry span IDs are 64 bits wide, so this is the first 8 bytes
+    /// of the decoded UUID.
+    ///
+    /// # Returns
+    ///
+    /// The first 8 decoded UUID bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// assert_eq!(suffix.to_otel_span_id(), suffix.to_uuid().as_bytes()[..8]);
+    /// ```
+    #[must_use]
+    pub fn to_otel_span_id(&self) -> [u8; 8] {
+        let [b0, b1, b2, b3, b4, b5, b6, b7, ..] = self.to_otel_trace_id();
+        [b0, b1, b2, b3, b4, b5, b6, b7]
+    }
+
+    /// Packs the ``TypeIdSuffix`` and a one-byte tag into a 17-byte array.
+    ///
+    /// The first byte is the tag and the remaining 16 bytes are the decoded UUID.
+    /// This is a compact storage format for typed IDs without a string prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag`: A caller-defined type tag.
+    ///
+    /// # Returns
+    ///
+    /// The tag followed by the 16 decoded UUID bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// let bytes = suffix.to_tagged_bytes(7);
+    /// assert_eq!(TypeIdSuffix::from_tagged_bytes(bytes), (7, suffix));
+    /// ```
+    #[must_use]
+    pub fn to_tagged_bytes(&self, tag: u8) -> [u8; 17] {
+        let mut bytes = [tag; 17];
+        bytes[1..].copy_from_slice(self.to_uuid().as_bytes());
+        bytes
+    }
+
+    /// Unpacks a tag and a ``TypeIdSuffix`` from a 17-byte array.
+    ///
+    /// This reverses `to_tagged_bytes`. Any 16 bytes form a valid UUID, so
+    /// this conversion cannot fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes`: The tag followed by 16 UUID bytes.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the tag and the ``TypeIdSuffix``.
+    #[must_use]
+    pub fn from_tagged_bytes(bytes: [u8; 17]) -> (u8, Self) {
+        let [tag, uuid_bytes @ ..] = bytes;
+        (tag, Uuid::from_bytes(uuid_bytes).into())
+    }
+
+    /// Renders the ``TypeIdSuffix`` as a full `TypeID` string with the given prefix.
+    ///
+    /// A non-empty prefix is joined to the suffix with an underscore
+    /// (`prefix_suffix`); an empty prefix yields the bare suffix.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix`: The `TypeID` prefix to prepend.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the rendered `TypeID` or a `PrefixError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PrefixError` if the prefix is longer than 63 characters,
+    /// contains characters other than lowercase ASCII letters and underscores,
+    /// or starts or ends with an underscore.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(suffix.to_typeid_string("user").unwrap(), "user_01h455vb4pex5vsknk084sn02q");
+    /// assert_eq!(suffix.to_typeid_string("").unwrap(), "01h455vb4pex5vsknk084sn02q");
+    /// assert!(suffix.to_typeid_string("User").is_err());
+    /// ```
+    pub fn to_typeid_string(&self, prefix: &str) -> Result<String, PrefixError> {
+        validate_prefix(prefix)?;
+        if prefix.is_empty() {
+            return Ok(self.to_string());
+        }
+        Ok(format!("{prefix}_{self}"))
+    }
+
+    /// Parses a full `TypeID` string into its prefix and ``TypeIdSuffix``.
+    ///
+    /// The input is split at the last underscore. The part after it is parsed
+    /// with `from_str`, and the part before it is checked with
+    /// `prefix::validate_prefix`. An underscore with nothing before it is
+    /// rejected, since a `TypeID` without a prefix is written without one.
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: A `TypeID` string such as `prefix_01h455vb4pex5vsknk084sn02q`,
+    ///   or a bare suffix.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the prefix (`None` when the input has no underscore)
+    /// and the parsed ``TypeIdSuffix``, or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DecodeError::InvalidPrefix` with `PrefixError::EmptyWithSeparator`
+    /// if the input starts with the separator, or with the error from
+    /// `validate_prefix` if the prefix is malformed. Otherwise, returns the same
+    /// errors as `from_str` for the part after the last underscore.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let (prefix, suffix) = TypeIdSuffix::from_typeid_str("pre_fix_01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(prefix, Some("pre_fix"));
+    /// assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+    ///
+    /// let (prefix, _) = TypeIdSuffix::from_typeid_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(prefix, None);
+    ///
+    /// assert_eq!(
+    ///     TypeIdSuffix::from_typeid_str("_01h455vb4pex5vsknk084sn02q"),
+    ///     Err(DecodeError::InvalidPrefix(PrefixError::EmptyWithSeparator))
+    /// );
+    /// ```
+    pub fn from_typeid_str(input: &str) -> Result<(Option<&str>, Self), DecodeError> {
+        let Some((prefix, suffix)) = input.rsplit_once('_') else {
+            return Ok((None, Self::from_str(input)?));
+        };
+        if prefix.is_empty() {
+            return Err(DecodeError::InvalidPrefix(PrefixError::EmptyWithSeparator));
+        }
+        validate_prefix(prefix).map_err(DecodeError::InvalidPrefix)?;
+        Ok((Some(prefix), Self::from_str(suffix)?))
+    }
+
+    /// Parses a string literal into a ``TypeIdSuffix`` at compile time.
+    ///
+    /// This performs the same validation as `from_str`, but in a `const` context,
+    /// so well-known IDs can be declared as constants. When used to initialize a
+    /// `const` or `static`, an invalid literal fails the build.
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: The string slice to parse.
+    ///
+    /// # Returns
+    ///
+    /// The parsed ``TypeIdSuffix``.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is not a valid ``TypeIdSuffix``. In a `const` context this
+    /// is a compile-time error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// const SYSTEM: TypeIdSuffix = TypeIdSuffix::from_str_const("01h455vb4pex5vsknk084sn02q");
+    /// assert_eq!(SYSTEM.to_string(), "01h455vb4pex5vsknk084sn02q");
+    /// ```
+    ///
+    /// An invalid literal does not compile:
+    ///
+    /// ```compile_fail
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// const BAD: TypeIdSuffix = TypeIdSuffix::from_str_const("not a suffix");
+    /// ```
+    #[must_use]
+    pub const fn from_str_const(input: &str) -> Self {
+        let bytes = input.as_bytes();
+        assert!(bytes.len() == 26, "Suffix must be exactly 26 characters long");
+
+        let mut encoded_bytes = [0u8; 26];
+        let mut i = 0;
+        while i < 26 {
+            encoded_bytes[i] = bytes[i];
+            i += 1;
+        }
+
+        match Self::from_encoded(encoded_bytes) {
+            Ok(suffix) => suffix,
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength)) => {
+                panic!("Suffix must be exactly 26 characters long")
+            }
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter)) => {
+                panic!("Suffix contains non-ASCII characters")
+            }
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter)) => {
+                panic!("First character of suffix must be '7' or less")
+            }
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidCharacter)) => {
+                panic!("Suffix contains characters not in the base32 alphabet")
+            }
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::NonCanonical)) => {
+                panic!("Suffix is not in canonical form")
+            }
+            Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVersion)) => {
+                panic!("UUID version is not valid for this TypeID")
+            }
+            Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVariant)) => {
+                panic!("UUID variant is not RFC4122")
+            }
+            Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidBytes)) => {
+                panic!("UUID bytes are invalid")
+            }
+            Err(DecodeError::InvalidUuid(InvalidUuidReason::GenerationUnavailable)) => {
+                panic!("UUID generation is unavailable in this environment")
+            }
+            Err(DecodeError::InvalidUuid(InvalidUuidReason::NilNotAllowed)) => {
+                panic!("UUID must not be nil")
+            }
+            Err(DecodeError::InvalidPrefix(_)) => {
+                panic!("Invalid TypeID prefix")
+            }
+        }
+    }
+
+    /// Encodes 16 UUID bytes into a ``TypeIdSuffix`` at compile time.
+    ///
+    /// This is the `const` counterpart of `From<[u8; 16]>`, for declaring the
+    /// suffixes of well-known UUIDs as constants. Like that conversion, it
+    /// accepts any 16 bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes`: The big-endian bytes of the UUID.
+    ///
+    /// # Returns
+    ///
+    /// The ``TypeIdSuffix`` encoding the bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// const SYSTEM_ID: TypeIdSuffix = TypeIdSuffix::from_uuid_const(*Uuid::from_u128(1).as_bytes());
+    /// assert_eq!(SYSTEM_ID.to_string(), "00000000000000000000000001");
+    /// ```
+    #[must_use]
+    pub const fn from_uuid_const(bytes: [u8; 16]) -> Self {
+        Self(encode::<16, 26>(&bytes))
+    }
+
+    /// Parses a string slice into a ``TypeIdSuffix``, requiring canonical form.
+    ///
+    /// After the normal `from_str` validation, the decoded UUID is re-encoded and
+    /// compared with the input. Any mismatch is rejected, guaranteeing a single
+    /// stable representation per UUID.
+    ///
+    /// With the first-character and overflow checks in place, every string
+    /// accepted by `from_str` is already canonical, so this is a
+    /// belt-and-braces check rather than a stricter grammar.
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: The string slice to parse.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the parsed ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_str`, plus
+    /// `InvalidSuffixReason::NonCanonical` if re-encoding yields a different string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from_str_canonical("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+    /// ```
+    pub fn from_str_canonical(input: &str) -> Result<Self, DecodeError> {
+        let suffix = Self::from_str(input)?;
+        if encode_base32(suffix.to_uuid().as_bytes()) != suffix.0 {
+            return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::NonCanonical));
+        }
+        Ok(suffix)
+    }
+
+    /// Parses a ``TypeIdSuffix`` from untrusted input with an up-front length guard.
+    ///
+    /// Inputs longer than `max_len` bytes are rejected before any other processing.
+    /// The input may be a bare suffix or a full `TypeID`; as with `from_typeid_str`,
+    /// everything after the last underscore is parsed, and the prefix is validated
+    /// and then discarded.
+    /// Pass `26` to accept only bare suffixes, or `90` (a 63-character prefix,
+    /// the separator, and the suffix) to accept any full `TypeID`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: The string slice to parse.
+    /// * `max_len`: The maximum accepted input length in bytes.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the parsed ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidSuffixReason::InvalidLength` if the input is longer than
+    /// `max_len`, and otherwise the same errors as `from_typeid_str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from_str_guarded("user_01h455vb4pex5vsknk084sn02q", 90).unwrap();
+    /// assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+    ///
+    /// assert!(TypeIdSuffix::from_str_guarded(&"a".repeat(1024), 90).is_err());
+    /// ```
+    pub fn from_str_guarded(input: &str, max_len: usize) -> Result<Self, DecodeError> {
+        if input.len() > max_len {
+            return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength));
+        }
+        Self::from_typeid_str(input).map(|(_, suffix)| suffix)
+    }
+
+    /// Parses a ``TypeIdSuffix`` under the rules of a specific `TypeID` specification version.
+    ///
+    /// `from_str` follows the current specification. Use this for data that must
+    /// conform to an earlier draft; see `SpecVersion` for the differences.
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: The string slice to parse.
+    /// * `spec`: The specification version whose rules apply.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the parsed ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_str`, plus `DecodeError::InvalidUuid` if
+    /// the decoded UUID is not permitted by `spec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let v7 = TypeIdSuffix::new::<V7>();
+    /// assert!(TypeIdSuffix::from_str_for_spec(&v7, SpecVersion::V0_2).is_ok());
+    ///
+    /// let v4 = TypeIdSuffix::new::<V4>();
+    /// assert!(TypeIdSuffix::from_str_for_spec(&v4, SpecVersion::V0_3).is_ok());
+    /// assert_eq!(
+    ///     TypeIdSuffix::from_str_for_spec(&v4, SpecVersion::V0_2),
+    ///     Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVersion))
+    /// );
+    /// ```
+    pub fn from_str_for_spec(input: &str, spec: SpecVersion) -> Result<Self, DecodeError> {
+        let (suffix, uuid) = Self::parse_with_uuid(input)?;
+        spec.check_uuid(&uuid).map_err(DecodeError::InvalidUuid)?;
+        Ok(suffix)
+    }
+
+    /// Parses a string slice into a ``TypeIdSuffix``, rejecting the nil UUID.
+    ///
+    /// `from_str` accepts the all-zero suffix, as the specification requires. Use
+    /// this where a nil ID can only mean an uninitialized value slipped through.
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: The string slice to parse.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the parsed ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_str`, plus `InvalidUuidReason::NilNotAllowed`
+    /// if the decoded UUID is nil.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// assert!(TypeIdSuffix::from_str_non_nil("01h455vb4pex5vsknk084sn02q").is_ok());
+    /// assert!(TypeIdSuffix::from_str_non_nil("00000000000000000000000000").is_err());
+    /// ```
+    pub fn from_str_non_nil(input: &str) -> Result<Self, DecodeError> {
+        let (suffix, uuid) = Self::parse_with_uuid(input)?;
+        if uuid.is_nil() {
+            return Err(DecodeError::InvalidUuid(InvalidUuidReason::NilNotAllowed));
+        }
+        Ok(suffix)
+    }
+
+    /// Parses a ``TypeIdSuffix`` from a sequence of characters, such as a tokenizer's output.
+    ///
+    /// Exactly 26 characters are expected. One more character is read to detect
+    /// input that is too long, so at most 27 are consumed and nothing is allocated.
+    ///
+    /// # Arguments
+    ///
+    /// * `chars`: The characters to parse.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the parsed ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidSuffixReason::NonAsciiCharacter` as soon as a non-ASCII
+    /// character is read, `InvalidSuffixReason::InvalidLength` if there are fewer
+    /// or more than 26 characters, and otherwise the same errors as `from_str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from_chars("01h455vb4pex5vsknk084sn02q".chars()).unwrap();
+    /// assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+    /// ```
+    pub fn from_chars<I: IntoIterator<Item = char>>(chars: I) -> Result<Self, DecodeError> {
+        let mut chars = chars.into_iter();
+        let mut encoded_bytes = [0u8; 26];
+        for byte in &mut encoded_bytes {
+            let c = chars.next().ok_or(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))?;
+            if !c.is_ascii() {
+                return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter));
+            }
+            *byte = c as u8;
+        }
+        if chars.next().is_some() {
+            return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength));
+        }
+        Self::decode_encoded(&encoded_bytes).map(|_| Self(encoded_bytes))
     }
 
-    /// Converts the `TypeIdSuffix` to a UUID.
+    /// Parses a ``TypeIdSuffix`` from an `OsStr`, such as a path component.
     ///
-    /// This method decodes the base32-encoded suffix back into a UUID.
+    /// # Arguments
     ///
-    /// # Returns
+    /// * `input`: The OS string to parse.
     ///
-    /// The `Uuid` represented by this `TypeIdSuffix`.
+    /// # Returns
     ///
-    /// # Panics
+    /// A `Result` containing either the parsed ``TypeIdSuffix`` or a `DecodeError`.
     ///
-    /// This method uses `expect()` internally, but it should never panic under normal circumstances.
-    /// A panic would indicate a serious internal inconsistency in the `TypeIdSuffix` struct,
-    /// which should be reported as a bug in the library.
+    /// # Errors
     ///
-    /// The reason it shouldn't panic is that:
-    /// 1. The `TypeIdSuffix` is always created from a valid UUID or a valid base32 string.
-    /// 2. All creation methods (`new()`, `from_str()`, `From<Uuid>`) perform thorough validation.
-    /// 3. The internal representation is immutable after creation.
+    /// Returns `InvalidSuffixReason::NonAsciiCharacter` if the input is not
+    /// valid UTF-8, and otherwise the same errors as `from_str`.
     ///
     /// # Examples
     ///
     /// ```
+    /// use std::path::Path;
     /// use typeid_suffix::prelude::*;
     ///
-    /// let suffix = TypeIdSuffix::new::<V4>();
-    /// let uuid = suffix.to_uuid();
+    /// let path = Path::new("/data/01h455vb4pex5vsknk084sn02q");
+    /// let suffix = TypeIdSuffix::from_os_str(path.file_name().unwrap()).unwrap();
+    /// assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn to_uuid(&self) -> Uuid {
-        let decoded_bytes = decode_base32(&self.0).expect("This should never fail because we've already validated the input");
-        Uuid::from_bytes(decoded_bytes)
+    pub fn from_os_str(input: &OsStr) -> Result<Self, DecodeError> {
+        let input = input
+            .to_str()
+            .ok_or(DecodeError::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter))?;
+        Self::from_str(input)
     }
 
-    /// Returns a string slice of the ``TypeIdSuffix``.
+    /// Parses a string slice into a ``TypeIdSuffix`` and returns the decoded UUID alongside it.
     ///
-    /// This method provides a way to access the underlying string representation
-    /// of the ``TypeIdSuffix``.
+    /// This is equivalent to calling `from_str` followed by `to_uuid`, but the
+    /// base32 input is decoded only once.
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: The string slice to parse.
     ///
     /// # Returns
     ///
-    /// A string slice containing the base32-encoded ``TypeIdSuffix``.
+    /// A `Result` containing either the parsed ``TypeIdSuffix`` and its `Uuid`, or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_str`.
     ///
     /// # Examples
     ///
     /// ```
     /// use typeid_suffix::prelude::*;
     ///
-    /// let suffix = TypeIdSuffix::new::<V4>();
-    /// let suffix_str = suffix.as_ref();
-    /// assert_eq!(suffix_str.len(), 26);
+    /// let (suffix, uuid) = TypeIdSuffix::parse_with_uuid("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(uuid, suffix.to_uuid());
+    /// assert_eq!(uuid.to_string(), "01890a5d-ac96-774b-bcce-b302099a8057");
     /// ```
-    #[must_use]
-    #[inline]
-    fn as_str(&self) -> &str {
-        // SAFETY: This unwrap is safe because we know that the internal bytes
-        // are always valid ASCII characters, which are valid UTF-8
-        std::str::from_utf8(&self.0).unwrap()
-    }
-}
-
-impl TypeIdSuffix {
-    /// Checks if the ``TypeIdSuffix`` contains a V6 or V7 UUID.
-    fn is_sortable(&self) -> bool {
-        matches!(self.to_uuid().get_version(), Some(Version::SortMac | Version::SortRand))
+    pub fn parse_with_uuid(input: &str) -> Result<(Self, Uuid), DecodeError> {
+        let encoded_bytes: [u8; 26] = input
+            .as_bytes()
+            .try_into()
+            .map_err(|_| DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))?;
+        let uuid = Self::decode_encoded(&encoded_bytes)?;
+        Ok((Self(encoded_bytes), uuid))
     }
 }
 
 impl Ord for TypeIdSuffix {
+    /// Compares two ``TypeIdSuffix`` values by their encoded bytes.
+    ///
+    /// The base32 encoding is fixed-width and big-endian, so byte order is the
+    /// same as the order of the underlying UUIDs. For `UUIDv6` and `UUIDv7`
+    /// suffixes this is creation-time order; for other versions it is simply a
+    /// stable total order. No decoding is performed.
     fn cmp(&self, other: &Self) -> Ordering {
-        if self.is_sortable() && other.is_sortable() {
-            self.to_uuid().cmp(&other.to_uuid())
-        } else {
-            // Fall back to lexicographic ordering for non-V6/V7 UUIDs
-            self.0.cmp(&other.0)
-        }
+        self.0.cmp(&other.0)
     }
 }
 
@@ -175,17 +1778,83 @@ impl PartialOrd for TypeIdSuffix {
     }
 }
 
+impl PartialEq<Uuid> for TypeIdSuffix {
+    /// Compares the decoded UUID of the ``TypeIdSuffix`` with a `Uuid`.
+    fn eq(&self, other: &Uuid) -> bool {
+        self.to_u128() == other.as_u128()
+    }
+}
+
+impl PartialEq<TypeIdSuffix> for Uuid {
+    /// Compares a `Uuid` with the decoded UUID of a ``TypeIdSuffix``.
+    fn eq(&self, other: &TypeIdSuffix) -> bool {
+        self.as_u128() == other.to_u128()
+    }
+}
+
+impl PartialEq<[u8; 26]> for TypeIdSuffix {
+    /// Compares the encoded bytes of the ``TypeIdSuffix`` with a 26-byte array.
+    fn eq(&self, other: &[u8; 26]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<TypeIdSuffix> for [u8; 26] {
+    /// Compares a 26-byte array with the encoded bytes of a ``TypeIdSuffix``.
+    fn eq(&self, other: &TypeIdSuffix) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialEq<[u8]> for TypeIdSuffix {
+    /// Compares the encoded bytes of the ``TypeIdSuffix`` with a byte slice.
+    ///
+    /// Slices that are not exactly 26 bytes long are never equal.
+    fn eq(&self, other: &[u8]) -> bool {
+        other.len() == self.0.len() && self.0 == *other
+    }
+}
+
+impl PartialEq<TypeIdSuffix> for [u8] {
+    /// Compares a byte slice with the encoded bytes of a ``TypeIdSuffix``.
+    ///
+    /// Slices that are not exactly 26 bytes long are never equal.
+    fn eq(&self, other: &TypeIdSuffix) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<Uuid> for TypeIdSuffix {
+    /// Orders the decoded UUID of the ``TypeIdSuffix`` against a `Uuid`.
+    ///
+    /// This agrees with `Ord` for ``TypeIdSuffix``, which follows UUID byte order.
+    fn partial_cmp(&self, other: &Uuid) -> Option<Ordering> {
+        Some(self.to_u128().cmp(&other.as_u128()))
+    }
+}
+
+impl PartialOrd<TypeIdSuffix> for Uuid {
+    /// Orders a `Uuid` against the decoded UUID of a ``TypeIdSuffix``.
+    fn partial_cmp(&self, other: &TypeIdSuffix) -> Option<Ordering> {
+        Some(self.as_u128().cmp(&other.to_u128()))
+    }
+}
+
 impl Default for TypeIdSuffix {
-    /// Creates a default ``TypeIdSuffix`` using `UUIDv7`.
+    /// Creates a default ``TypeIdSuffix`` using `UUIDv7`, or `UUIDv4` with the `default-v4` feature.
     ///
-    /// This implementation uses `V7` (`UUIDv7`) as the default UUID version
-    /// for generating a ``TypeIdSuffix``.
+    /// `UUIDv7` is the default UUID version for generating a ``TypeIdSuffix``.
+    /// If the `default-v4` feature is enabled, `UUIDv4` is used instead, and it
+    /// takes precedence over `default-v7` when both are enabled.
     ///
     /// # Returns
     ///
-    /// A new ``TypeIdSuffix`` instance generated from a `UUIDv7`.
+    /// A new ``TypeIdSuffix`` instance generated from the selected UUID version.
     fn default() -> Self {
-        Self::new::<V7>()
+        #[cfg(feature = "default-v4")]
+        return Self::new::<crate::versions::V4>();
+        #[cfg(not(feature = "default-v4"))]
+        return Self::new::<crate::versions::V7>();
     }
 }
 
@@ -209,9 +1878,99 @@ impl Borrow<str> for TypeIdSuffix {
     }
 }
 
+impl Hash for TypeIdSuffix {
+    /// Hashes the ``TypeIdSuffix`` as its string representation.
+    ///
+    /// Because ``TypeIdSuffix`` implements `Borrow<str>`, its hash must match the
+    /// hash of the equivalent `str` so that hashed collections keyed by
+    /// ``TypeIdSuffix`` can be queried with a `&str`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
 impl fmt::Display for TypeIdSuffix {
+    /// Formats the ``TypeIdSuffix`` as its canonical lowercase string.
+    ///
+    /// With the alternate flag (`{:#}`), the suffix is written in uppercase
+    /// instead, without allocating. Width, fill, and alignment are honored as
+    /// for `str`, so a width above 26 pads the suffix for column-aligned output.
+    /// Unlike `str`, precision is ignored: the suffix is never truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    /// assert_eq!(format!("{suffix}"), "01h455vb4pex5vsknk084sn02q");
+    /// assert_eq!(format!("{suffix:#}"), "01H455VB4PEX5VSKNK084SN02Q");
+    /// assert_eq!(format!("[{suffix:>28}]"), "[  01h455vb4pex5vsknk084sn02q]");
+    /// assert_eq!(format!("{suffix:.8}"), "01h455vb4pex5vsknk084sn02q");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let mut upper = self.0;
+            upper.make_ascii_uppercase();
+            pad_untruncated(f, std::str::from_utf8(&upper).map_err(|_| fmt::Error)?)
+        } else {
+            pad_untruncated(f, self)
+        }
+    }
+}
+
+/// Writes `s` padded to the formatter's width, fill, and alignment.
+///
+/// This is `Formatter::pad` without precision, which would otherwise truncate
+/// the suffix into an invalid one. Like `str`, it aligns left by default.
+fn pad_untruncated(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+    let (before, after) = match f.align() {
+        None | Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
+
+impl fmt::LowerHex for TypeIdSuffix {
+    /// Formats the decoded UUID as 32 lowercase hexadecimal digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from(Uuid::from_u128(0xab));
+    /// assert_eq!(format!("{suffix:x}"), "000000000000000000000000000000ab");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:032x}", self.to_u128())
+    }
+}
+
+impl fmt::UpperHex for TypeIdSuffix {
+    /// Formats the decoded UUID as 32 uppercase hexadecimal digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::from(Uuid::from_u128(0xab));
+    /// assert_eq!(format!("{suffix:X}"), "000000000000000000000000000000AB");
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(self)
+        write!(f, "{:032X}", self.to_u128())
     }
 }
 
@@ -251,6 +2010,54 @@ impl From<TypeIdSuffix> for Uuid {
     }
 }
 
+impl From<TypeIdSuffix> for String {
+    /// Converts a ``TypeIdSuffix`` into its 26-character `String` representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: The ``TypeIdSuffix`` to convert.
+    ///
+    /// # Returns
+    ///
+    /// The suffix as an owned `String`.
+    #[allow(unsafe_code)]
+    fn from(value: TypeIdSuffix) -> Self {
+        // SAFETY: The internal bytes are always ASCII (see `as_str`), including after
+        // `Zeroize` overwrites them with NUL bytes, and ASCII is valid UTF-8
+        unsafe { Self::from_utf8_unchecked(value.0.to_vec()) }
+    }
+}
+
+impl From<&TypeIdSuffix> for String {
+    /// Converts a reference to a ``TypeIdSuffix`` into its 26-character `String` representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: A reference to the ``TypeIdSuffix`` to convert.
+    ///
+    /// # Returns
+    ///
+    /// The suffix as an owned `String`.
+    fn from(value: &TypeIdSuffix) -> Self {
+        Self::from(value.clone())
+    }
+}
+
+impl From<TypeIdSuffix> for [u8; 26] {
+    /// Converts a ``TypeIdSuffix`` into its 26 ASCII bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: The ``TypeIdSuffix`` to convert.
+    ///
+    /// # Returns
+    ///
+    /// The encoded suffix bytes, as returned by `to_array`.
+    fn from(value: TypeIdSuffix) -> Self {
+        value.0
+    }
+}
+
 impl FromStr for TypeIdSuffix {
     type Err = DecodeError;
 
@@ -284,23 +2091,55 @@ impl FromStr for TypeIdSuffix {
     ///
     /// let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
     /// ```
+    ///
+    /// With the `instrument` feature, each call runs in a `trace`-level
+    /// `from_str` span recording `input_len` and, on success, the decoded UUID
+    /// `version` number.
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        if input.len() != 26 {
-            return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength));
-        }
-        if !input.is_ascii() {
-            return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter));
-        }
-        if input.as_bytes()[0] > b'7' {
-            return Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter));
-        }
-        let encoded_bytes: [u8; 26] = input.as_bytes().try_into().map_err(|_| DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))?;
-        let decoded_bytes = decode_base32(&encoded_bytes)?;
-        let uuid = Uuid::from_bytes(decoded_bytes);
-        if !Self::is_valid_uuid(&uuid) {
-            return Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVersion));
+        #[cfg(feature = "instrument")]
+        let span = tracing::trace_span!("from_str", input_len = input.len(), version = tracing::field::Empty);
+        #[cfg(feature = "instrument")]
+        let _entered = span.enter();
+
+        let parsed = Self::parse_with_uuid(input);
+
+        #[cfg(feature = "metrics")]
+        if let Err(error) = &parsed {
+            crate::metrics::record_decode_error(error);
         }
-        Ok(Self(encoded_bytes))
+
+        let (suffix, uuid) = parsed?;
+
+        #[cfg(feature = "instrument")]
+        span.record("version", uuid.get_version_num());
+        #[cfg(not(feature = "instrument"))]
+        let _ = uuid;
+
+        Ok(suffix)
+    }
+}
+
+impl TryFrom<String> for TypeIdSuffix {
+    type Error = DecodeError;
+
+    /// Parses an owned `String` into a ``TypeIdSuffix``, consuming it.
+    ///
+    /// The suffix is stored in a fixed array, so the `String` is dropped once
+    /// it has been validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: The string to parse.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing either the parsed ``TypeIdSuffix`` or a `DecodeError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `from_str`.
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
     }
 }
 
@@ -333,6 +2172,61 @@ impl From<Uuid> for TypeIdSuffix {
     }
 }
 
+impl From<&Uuid> for TypeIdSuffix {
+    /// Converts a reference to a Uuid into a ``TypeIdSuffix``.
+    ///
+    /// This implementation allows for conversion from a borrowed Uuid, such as a
+    /// struct field, without copying it first.
+    ///
+    /// # Arguments
+    ///
+    /// * `value`: A reference to the Uuid to convert.
+    ///
+    /// # Returns
+    ///
+    /// A new ``TypeIdSuffix`` instance representing the given Uuid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let uuid = Uuid::now_v7();
+    /// let suffix = TypeIdSuffix::from(&uuid);
+    /// assert_eq!(suffix.to_uuid(), uuid);
+    /// ```
+    fn from(value: &Uuid) -> Self {
+        Self(encode_base32(value.as_bytes()))
+    }
+}
+
+impl From<[u8; 16]> for TypeIdSuffix {
+    /// Converts 16 raw UUID bytes into a ``TypeIdSuffix``.
+    ///
+    /// The bytes are encoded directly, without building a `Uuid` first. Like
+    /// `From<Uuid>`, this accepts any 16 bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes`: The big-endian bytes of the UUID.
+    ///
+    /// # Returns
+    ///
+    /// A new ``TypeIdSuffix`` instance encoding the bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let uuid = Uuid::new_v4();
+    /// assert_eq!(TypeIdSuffix::from(*uuid.as_bytes()), TypeIdSuffix::from(uuid));
+    /// ```
+    fn from(bytes: [u8; 16]) -> Self {
+        Self(encode_base32(&bytes))
+    }
+}
+
 #[cfg(feature = "serde")]
 impl Serialize for TypeIdSuffix {
     /// Serializes the `TypeIdSuffix` as its string representation.
@@ -361,13 +2255,17 @@ impl Serialize for TypeIdSuffix {
 impl<'de> Deserialize<'de> for TypeIdSuffix {
     /// Deserializes a `TypeIdSuffix` from its string representation.
     ///
-    /// This expects a string that is a valid `TypeID` suffix.
+    /// Human-readable formats such as JSON may also hold an array of the 16 UUID
+    /// bytes. Binary formats are read as a string, matching what `Serialize`
+    /// writes; fields stored as raw UUID bytes can opt in to that form with the
+    /// `serde_uuid_bytes` helpers.
     ///
     /// # Errors
     ///
     /// Returns an error if the string is not a valid `TypeIdSuffix`
     /// (e.g., incorrect length, invalid characters, invalid first character,
-    /// or decodes to an invalid UUID variant/version).
+    /// or decodes to an invalid UUID variant/version), or if a byte array is
+    /// not 16 long or fails the same UUID validation as `from_uuid`.
     ///
     /// # Examples
     ///
@@ -378,6 +2276,9 @@ impl<'de> Deserialize<'de> for TypeIdSuffix {
     /// let suffix_str = "\"01h455vb4pex5vsknk084sn02q\""; // JSON string
     /// let deserialized: TypeIdSuffix = serde_json::from_str(suffix_str).unwrap();
     ///
+    /// let uuid_bytes = serde_json::to_string(deserialized.to_uuid().as_bytes()).unwrap();
+    /// assert_eq!(serde_json::from_str::<TypeIdSuffix>(&uuid_bytes).unwrap(), deserialized);
+    ///
     /// let invalid_suffix_str = "\"invalid\"";
     /// let result: Result<TypeIdSuffix, _> = serde_json::from_str(invalid_suffix_str);
     /// assert!(result.is_err());
@@ -387,7 +2288,101 @@ impl<'de> Deserialize<'de> for TypeIdSuffix {
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        Self::from_str(&s).map_err(serde::de::Error::custom)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(TypeIdSuffixVisitor)
+        } else {
+            deserializer.deserialize_str(TypeIdSuffixVisitor)
+        }
+    }
+}
+
+/// Accepts a ``TypeIdSuffix`` as a string, a byte buffer, or a sequence of 16 bytes.
+///
+/// Shared with `serde_uuid_bytes`, but not part of the public API. The explicit
+/// `pub(crate)` keeps it that way even if this module is ever re-exported.
+#[cfg(feature = "serde")]
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) struct TypeIdSuffixVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for TypeIdSuffixVisitor {
+    type Value = TypeIdSuffix;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a 26-character TypeID suffix or 16 UUID bytes")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        TypeIdSuffix::from_str(value).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        match value.len() {
+            16 => TypeIdSuffix::from_uuid(Uuid::from_slice(value).map_err(E::custom)?).map_err(E::custom),
+            26 => TypeIdSuffix::from_buffer_at(value, 0).map_err(E::custom),
+            len => Err(E::invalid_length(len, &self)),
+        }
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0u8; 16];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(serde::de::Error::invalid_length(17, &self));
+        }
+        TypeIdSuffix::from_uuid(Uuid::from_bytes(bytes)).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedTypeIdSuffix {
+    /// Returns a string slice of the archived ``TypeIdSuffix``.
+    ///
+    /// The archived form stores the same 26 base32 bytes as ``TypeIdSuffix``,
+    /// so this reads directly from the archive without copying.
+    ///
+    /// Archives accessed through `rkyv::access` have been verified to hold a
+    /// valid suffix, which is ASCII, so the bytes are not checked again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "rkyv")] {
+    /// use typeid_suffix::prelude::*;
+    ///
+    /// let suffix = TypeIdSuffix::default();
+    /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&suffix).unwrap();
+    /// let archived = rkyv::access::<ArchivedTypeIdSuffix, rkyv::rancor::Error>(&bytes).unwrap();
+    /// assert_eq!(archived.as_str(), suffix.as_ref());
+    /// # }
+    /// ```
+    #[must_use]
+    #[allow(unsafe_code)]
+    pub fn as_str(&self) -> &str {
+        debug_assert!(self.0.is_ascii(), "ArchivedTypeIdSuffix bytes must be ASCII");
+        // SAFETY: `Verify` rejects any archive whose bytes are not a valid suffix, and
+        // accessing an archive without verification requires the caller to guarantee
+        // that it would have passed. A valid suffix is ASCII, which is valid UTF-8
+        unsafe { std::str::from_utf8_unchecked(&self.0) }
     }
-}
\ No newline at end of file
+}
+
+// SAFETY: `verify` only performs additional checks on bytes that `CheckBytes`
+// has already validated as a `[u8; 26]`; it never accepts anything more.
+#[cfg(feature = "rkyv")]
+#[allow(unsafe_code)]
+unsafe impl<C> rkyv::bytecheck::Verify<C> for ArchivedTypeIdSuffix
+where
+    C: rkyv::rancor::Fallible + ?Sized,
+    C::Error: rkyv::rancor::Source,
+{
+    /// Verifies that the archived bytes form a valid ``TypeIdSuffix``.
+    ///
+    /// This applies the same validation as `from_str`: every byte must be in the
+    /// base32 alphabet and the first byte must be `'7'` or less.
+    fn verify(&self, _context: &mut C) -> Result<(), C::Error> {
+        TypeIdSuffix::from_encoded(self.0).map(|_| ()).map_err(rkyv::rancor::Source::new)
+    }
+}
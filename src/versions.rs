@@ -7,20 +7,71 @@
 
 use std::ops::Deref;
 
-use uuid::Uuid;
+use uuid::{Uuid, Version};
+
+use crate::errors::{DecodeError, InvalidUuidReason};
+use crate::typeid_suffix::TypeIdSuffix;
 
 /// Trait for UUID versions used in `TypeID`.
 ///
 /// This trait is implemented by all UUID version structs in this module,
 /// allowing them to be used interchangeably where a UUID version is required.
-pub trait UuidVersion: Deref<Target=Uuid> {}
+pub trait UuidVersion: Deref<Target=Uuid> {
+    /// The UUID version this type generates, or `None` if it is not tied to one.
+    ///
+    /// This defaults to `None` so that existing implementations outside this
+    /// crate keep compiling; such versions accept any UUID in `validate`.
+    const VERSION: Option<Version> = None;
+
+    /// Checks that a UUID has the version this type represents.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidUuidReason::InvalidVersion` if `VERSION` is set and the
+    /// UUID has a different version.
+    fn validate(uuid: &Uuid) -> Result<(), InvalidUuidReason> {
+        match Self::VERSION {
+            Some(version) if uuid.get_version() != Some(version) => Err(InvalidUuidReason::InvalidVersion),
+            _ => Ok(()),
+        }
+    }
+
+    /// Generates a UUID of this version, reporting an unavailable generator as an error.
+    ///
+    /// The default implementation fails up front on targets without a system
+    /// clock or random number generator, which is `wasm32-unknown-unknown`
+    /// unless the `wasm` feature routes both through JavaScript. Everywhere else
+    /// it returns the UUID from `Default`. Versions that need neither, such as
+    /// the name-based ones, override it to always succeed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidUuidReason::GenerationUnavailable` if this version cannot
+    /// be generated on the current target.
+    fn try_generate() -> Result<Uuid, InvalidUuidReason>
+    where
+        Self: Default,
+    {
+        if GENERATOR_AVAILABLE {
+            Ok(*Self::default())
+        } else {
+            Err(InvalidUuidReason::GenerationUnavailable)
+        }
+    }
+}
+
+// `wasm32-unknown-unknown` has no clock or random number source of its own, and
+// the `uuid` generators panic there unless its `js` feature is enabled
+const GENERATOR_AVAILABLE: bool = !cfg!(all(target_arch = "wasm32", target_os = "unknown", not(feature = "wasm")));
 
 /// Represents a Version 1 UUID (time-based).
 ///
 /// Version 1 UUIDs are generated using a timestamp and node ID.
 pub struct V1(Uuid);
 
-impl UuidVersion for V1 {}
+impl UuidVersion for V1 {
+    const VERSION: Option<Version> = Some(Version::Mac);
+}
 
 impl Default for V1 {
     /// Creates a new Version 1 UUID using the current timestamp.
@@ -42,7 +93,14 @@ impl Deref for V1 {
 /// Version 3 UUIDs are generated by hashing a namespace and name using MD5.
 pub struct V3(Uuid);
 
-impl UuidVersion for V3 {}
+impl UuidVersion for V3 {
+    const VERSION: Option<Version> = Some(Version::Md5);
+
+    fn try_generate() -> Result<Uuid, InvalidUuidReason> {
+        // Name-based and nil UUIDs need neither a clock nor a random number source
+        Ok(*Self::default())
+    }
+}
 
 impl Default for V3 {
     /// Creates a new Version 3 UUID using the DNS namespace and default name.
@@ -64,7 +122,9 @@ impl Deref for V3 {
 /// Version 4 UUIDs are generated using random or pseudo-random numbers.
 pub struct V4(Uuid);
 
-impl UuidVersion for V4 {}
+impl UuidVersion for V4 {
+    const VERSION: Option<Version> = Some(Version::Random);
+}
 
 impl Default for V4 {
     /// Creates a new random Version 4 UUID.
@@ -86,7 +146,14 @@ impl Deref for V4 {
 /// Version 5 UUIDs are generated by hashing a namespace and name using SHA-1.
 pub struct V5(Uuid);
 
-impl UuidVersion for V5 {}
+impl UuidVersion for V5 {
+    const VERSION: Option<Version> = Some(Version::Sha1);
+
+    fn try_generate() -> Result<Uuid, InvalidUuidReason> {
+        // Name-based and nil UUIDs need neither a clock nor a random number source
+        Ok(*Self::default())
+    }
+}
 
 impl Deref for V5 {
     type Target = Uuid;
@@ -108,7 +175,9 @@ impl Default for V5 {
 /// Version 6 UUIDs are similar to Version 1, but with improved privacy and monotonicity.
 pub struct V6(Uuid);
 
-impl UuidVersion for V6 {}
+impl UuidVersion for V6 {
+    const VERSION: Option<Version> = Some(Version::SortMac);
+}
 
 impl Deref for V6 {
     type Target = Uuid;
@@ -138,7 +207,9 @@ impl Deref for V7 {
     }
 }
 
-impl UuidVersion for V7 {}
+impl UuidVersion for V7 {
+    const VERSION: Option<Version> = Some(Version::SortRand);
+}
 
 impl Default for V7 {
     /// Creates a new Version 7 UUID using the current timestamp.
@@ -160,11 +231,83 @@ impl Deref for Nil {
     }
 }
 
-impl UuidVersion for Nil {}
+impl UuidVersion for Nil {
+    const VERSION: Option<Version> = Some(Version::Nil);
+
+    fn try_generate() -> Result<Uuid, InvalidUuidReason> {
+        // Name-based and nil UUIDs need neither a clock nor a random number source
+        Ok(*Self::default())
+    }
+}
 
 impl Default for Nil {
     /// Creates a new Nil UUID (all zeros).
     fn default() -> Self {
         Self(Uuid::nil())
     }
-}
\ No newline at end of file
+}
+
+/// Defines a `Uuid` newtype that is known to have a specific version.
+macro_rules! versioned_uuid {
+    ($(#[$meta:meta])* $name:ident, $version:ident) => {
+        $(#[$meta])*
+        ///
+        /// The only way to obtain one from a ``TypeIdSuffix`` is `TryFrom`, which
+        /// checks the version, so a parameter of this type documents and enforces
+        /// the version a function expects.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(Uuid);
+
+        impl Deref for $name {
+            type Target = Uuid;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl From<$name> for Uuid {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl TryFrom<&TypeIdSuffix> for $name {
+            type Error = DecodeError;
+
+            /// Decodes the ``TypeIdSuffix``, checking that it holds a UUID of this version.
+            ///
+            /// # Errors
+            ///
+            /// Returns `InvalidUuidReason::InvalidVersion` if the UUID has another version.
+            fn try_from(value: &TypeIdSuffix) -> Result<Self, Self::Error> {
+                value.to_uuid_as::<$version>().map(Self)
+            }
+        }
+    };
+}
+
+versioned_uuid!(
+    /// A `Uuid` known to be Version 1 (time-based).
+    V1Uuid, V1
+);
+versioned_uuid!(
+    /// A `Uuid` known to be Version 3 (name-based, MD5 hash).
+    V3Uuid, V3
+);
+versioned_uuid!(
+    /// A `Uuid` known to be Version 4 (random).
+    V4Uuid, V4
+);
+versioned_uuid!(
+    /// A `Uuid` known to be Version 5 (name-based, SHA-1 hash).
+    V5Uuid, V5
+);
+versioned_uuid!(
+    /// A `Uuid` known to be Version 6 (reordered time-based).
+    V6Uuid, V6
+);
+versioned_uuid!(
+    /// A `Uuid` known to be Version 7 (time-ordered).
+    V7Uuid, V7
+);
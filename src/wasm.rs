@@ -0,0 +1,59 @@
+//! `wasm-bindgen` bindings for ``TypeIdSuffix``.
+//!
+//! These thin wrappers expose generation, validation, and UUID conversion to
+//! JavaScript, so a browser frontend built with `wasm-pack` can share the
+//! crate's base32 logic instead of reimplementing it.
+
+use std::str::FromStr;
+
+use ::wasm_bindgen::prelude::*;
+
+use crate::typeid_suffix::TypeIdSuffix;
+use crate::versions::V7;
+
+/// Generates a new ``TypeIdSuffix`` from a `UUIDv7`.
+///
+/// # Returns
+///
+/// The 26-character suffix string.
+#[wasm_bindgen]
+#[must_use]
+pub fn generate_v7() -> String {
+    TypeIdSuffix::new::<V7>().to_string()
+}
+
+/// Checks whether a string is a valid ``TypeIdSuffix``.
+///
+/// # Arguments
+///
+/// * `s`: The string to validate.
+///
+/// # Returns
+///
+/// `true` if `s` parses as a ``TypeIdSuffix``, `false` otherwise.
+#[wasm_bindgen]
+#[must_use]
+pub fn validate(s: &str) -> bool {
+    TypeIdSuffix::from_str(s).is_ok()
+}
+
+/// Converts a ``TypeIdSuffix`` string into its canonical UUID string.
+///
+/// # Arguments
+///
+/// * `s`: The suffix string to convert.
+///
+/// # Returns
+///
+/// The hyphenated lowercase UUID string.
+///
+/// # Errors
+///
+/// Returns the `DecodeError` message as a JavaScript string if `s` is not a
+/// valid ``TypeIdSuffix``.
+#[wasm_bindgen]
+pub fn to_uuid(s: &str) -> Result<String, JsValue> {
+    TypeIdSuffix::from_str(s)
+        .map(|suffix| suffix.to_uuid().to_string())
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
@@ -0,0 +1,21 @@
+//! `zeroize` support for ``TypeIdSuffix``.
+//!
+//! Zeroizing overwrites the 26 encoded bytes with zeros. The result is not a
+//! valid ``TypeIdSuffix`` (zero bytes are outside the base32 alphabet), so a
+//! zeroized value must only be dropped, never displayed or decoded. The zero
+//! bytes are still ASCII, so `as_str` and the `String` conversions stay sound.
+//!
+//! ``TypeIdSuffix`` does not implement `ZeroizeOnDrop`: a `Drop` impl would
+//! prevent moving the inner array out by value. Wrap the suffix in
+//! `zeroize::Zeroizing` to scrub it automatically on drop.
+
+use ::zeroize::Zeroize;
+
+use crate::typeid_suffix::TypeIdSuffix;
+
+impl Zeroize for TypeIdSuffix {
+    /// Overwrites the encoded bytes with zeros, invalidating the value.
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
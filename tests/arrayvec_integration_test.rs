@@ -0,0 +1,21 @@
+//! Integration tests for arrayvec functionality of `TypeIdSuffix`.
+//!
+//! These tests verify that `to_array_string` renders the same text as the
+//! heap-allocated string forms.
+
+#![cfg(feature = "arrayvec")]
+
+use std::str::FromStr;
+
+use typeid_suffix::prelude::*;
+
+#[test]
+fn test_to_array_string() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let inline = suffix.to_array_string();
+
+    assert_eq!(inline.as_str(), suffix.as_ref());
+    assert_eq!(inline.as_str(), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(inline.len(), 26);
+    assert_eq!(inline.capacity(), 26);
+}
@@ -0,0 +1,55 @@
+//! Integration tests for borsh functionality of `TypeIdSuffix`.
+//!
+//! These tests verify that `TypeIdSuffix` round-trips through Borsh using
+//! the compact 16-byte UUID representation.
+
+#![cfg(feature = "borsh")]
+
+use std::str::FromStr;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use typeid_suffix::prelude::*;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize, PartialEq)]
+struct Message {
+    id: TypeIdSuffix,
+    payload: String,
+}
+
+#[test]
+fn test_borsh_roundtrip() {
+    let suffix = TypeIdSuffix::default();
+
+    let bytes = borsh::to_vec(&suffix).unwrap();
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(bytes, suffix.to_uuid().as_bytes());
+
+    let deserialized: TypeIdSuffix = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(suffix, deserialized);
+}
+
+#[test]
+fn test_borsh_known_vector() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let uuid = Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057").unwrap();
+
+    assert_eq!(borsh::to_vec(&suffix).unwrap(), uuid.as_bytes());
+}
+
+#[test]
+fn test_borsh_in_struct() {
+    let message = Message {
+        id: TypeIdSuffix::new::<V4>(),
+        payload: "hello".to_string(),
+    };
+
+    let bytes = borsh::to_vec(&message).unwrap();
+    let deserialized: Message = borsh::from_slice(&bytes).unwrap();
+    assert_eq!(message, deserialized);
+}
+
+#[test]
+fn test_borsh_truncated_input() {
+    let bytes = borsh::to_vec(&TypeIdSuffix::default()).unwrap();
+    assert!(borsh::from_slice::<TypeIdSuffix>(&bytes[..15]).is_err());
+}
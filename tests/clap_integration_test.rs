@@ -0,0 +1,44 @@
+//! Integration tests for the `clap` feature of `TypeIdSuffix`.
+//!
+//! These tests parse command lines with a suffix argument and verify that
+//! valid suffixes are accepted and invalid ones rejected with a clear message.
+
+#![cfg(feature = "clap")]
+
+use clap::error::ErrorKind;
+use clap::{value_parser, Arg, Command};
+use typeid_suffix::prelude::*;
+
+fn command() -> Command {
+    Command::new("app").arg(Arg::new("id").long("id").value_name("ID").value_parser(TypeIdSuffixValueParser))
+}
+
+#[test]
+fn test_valid_suffix_is_parsed() {
+    let matches = command().try_get_matches_from(["app", "--id", "01h455vb4pex5vsknk084sn02q"]).unwrap();
+    let suffix = matches.get_one::<TypeIdSuffix>("id").unwrap();
+    assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+}
+
+#[test]
+fn test_invalid_suffix_is_rejected_with_reason() {
+    let error = command().try_get_matches_from(["app", "--id", "too_short"]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::ValueValidation);
+
+    let message = error.to_string();
+    assert!(message.contains("invalid value 'too_short' for '--id <ID>'"), "{message}");
+    assert!(message.contains("Suffix must be exactly 26 characters long"), "{message}");
+
+    let error = command().try_get_matches_from(["app", "--id", "81h455vb4pex5vsknk084sn02q"]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::ValueValidation);
+    assert!(error.to_string().contains("First character of suffix must be '7' or less"), "{error}");
+}
+
+#[test]
+fn test_value_parser_macro_selects_suffix_parser() {
+    let command = Command::new("app").arg(Arg::new("id").value_parser(value_parser!(TypeIdSuffix)));
+
+    assert!(command.clone().try_get_matches_from(["app", "01h455vb4pex5vsknk084sn02q"]).is_ok());
+    let error = command.try_get_matches_from(["app", "01h455vb4pex5vsknk084sn0!q"]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::ValueValidation);
+}
@@ -0,0 +1,53 @@
+//! Conformance tests against the reference implementation's test vectors.
+//!
+//! These tests run the bundled `valid.json` through `from_reference_json`.
+//! Several vectors (e.g. nil) are not RFC4122 UUIDs, so these tests are skipped
+//! when the non-compliant `strict-global` feature is enabled.
+
+#![cfg(all(feature = "conformance", not(feature = "strict-global")))]
+
+use typeid_suffix::conformance::{from_reference_json, ConformanceError};
+
+const VALID_JSON: &str = include_str!("valid.json");
+
+#[test]
+fn test_reference_valid_json() {
+    let vectors = from_reference_json(VALID_JSON).unwrap();
+    assert_eq!(vectors.len(), 9);
+
+    let (name, suffix) = vectors.iter().find(|(name, _)| name == "valid-uuidv7").unwrap();
+    assert_eq!(name, "valid-uuidv7");
+    assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+}
+
+#[test]
+fn test_reference_uuid_mismatch() {
+    let json = r#"[{"name": "wrong", "typeid": "00000000000000000000000001", "prefix": "", "uuid": "00000000-0000-0000-0000-000000000002"}]"#;
+    assert!(matches!(
+        from_reference_json(json),
+        Err(ConformanceError::UuidMismatch { name }) if name == "wrong"
+    ));
+}
+
+#[test]
+fn test_reference_decode_error() {
+    let json = r#"[{"name": "bad", "typeid": "prefix_8zzzzzzzzzzzzzzzzzzzzzzzzz", "prefix": "prefix", "uuid": "00000000-0000-0000-0000-000000000000"}]"#;
+    assert!(matches!(
+        from_reference_json(json),
+        Err(ConformanceError::Decode { name, .. }) if name == "bad"
+    ));
+}
+
+#[test]
+fn test_reference_invalid_prefix() {
+    let json = r#"[{"name": "upper", "typeid": "PREFIX_00000000000000000000000000", "prefix": "PREFIX", "uuid": "00000000-0000-0000-0000-000000000000"}]"#;
+    assert!(matches!(
+        from_reference_json(json),
+        Err(ConformanceError::InvalidPrefix { name, .. }) if name == "upper"
+    ));
+}
+
+#[test]
+fn test_reference_invalid_json() {
+    assert!(matches!(from_reference_json("{}"), Err(ConformanceError::Json(_))));
+}
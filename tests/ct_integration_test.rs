@@ -0,0 +1,23 @@
+//! Integration tests for constant-time comparison of `TypeIdSuffix`.
+//!
+//! These tests verify that `ct_eq` agrees with `PartialEq`.
+
+#![cfg(feature = "ct")]
+
+use std::str::FromStr;
+
+use subtle::ConstantTimeEq;
+use typeid_suffix::prelude::*;
+
+#[test]
+fn test_ct_eq_matches_eq() {
+    let a = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let same = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let last_differs = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02r").unwrap();
+    let first_differs = TypeIdSuffix::from_str("11h455vb4pex5vsknk084sn02q").unwrap();
+
+    for (left, right) in [(&a, &same), (&a, &last_differs), (&a, &first_differs), (&a, &a)] {
+        assert_eq!(bool::from(left.ct_eq(right)), left == right);
+        assert_eq!(bool::from(ConstantTimeEq::ct_eq(left, right)), left == right);
+    }
+}
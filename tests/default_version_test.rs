@@ -0,0 +1,24 @@
+//! Tests for the `default-v7` and `default-v4` features.
+//!
+//! These tests verify that `TypeIdSuffix::default()` generates the UUID version
+//! selected by the enabled features, while `new::<V>()` stays explicit.
+
+use typeid_suffix::prelude::*;
+
+#[test]
+#[cfg(not(feature = "default-v4"))]
+fn test_default_is_v7() {
+    assert_eq!(TypeIdSuffix::default().version(), Some(Version::SortRand));
+}
+
+#[test]
+#[cfg(feature = "default-v4")]
+fn test_default_is_v4() {
+    assert_eq!(TypeIdSuffix::default().version(), Some(Version::Random));
+}
+
+#[test]
+fn test_new_is_explicit() {
+    assert_eq!(TypeIdSuffix::new::<V7>().version(), Some(Version::SortRand));
+    assert_eq!(TypeIdSuffix::new::<V4>().version(), Some(Version::Random));
+}
@@ -0,0 +1,67 @@
+//! Integration tests for diesel functionality of `TypeIdSuffix`.
+//!
+//! These tests verify that `TypeIdSuffix` can be inserted into and selected
+//! from a text column through Diesel using an in-memory `SQLite` database.
+
+#![cfg(feature = "diesel")]
+
+use diesel::prelude::*;
+use diesel::sqlite::SqliteConnection;
+use typeid_suffix::prelude::*;
+
+diesel::table! {
+    /// Test table keyed by a suffix.
+    users (id) {
+        /// The user's suffix.
+        id -> Text,
+        /// The user's display name.
+        name -> Text,
+    }
+}
+
+#[derive(Debug, PartialEq, Queryable, Insertable, Selectable)]
+#[diesel(table_name = users)]
+struct User {
+    id: TypeIdSuffix,
+    name: String,
+}
+
+fn connect() -> SqliteConnection {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query("CREATE TABLE users (id TEXT PRIMARY KEY NOT NULL, name TEXT NOT NULL)")
+        .execute(&mut conn)
+        .unwrap();
+    conn
+}
+
+#[test]
+fn test_diesel_sqlite_roundtrip() {
+    let mut conn = connect();
+    let user = User { id: TypeIdSuffix::default(), name: "Test User".to_string() };
+
+    diesel::insert_into(users::table).values(&user).execute(&mut conn).unwrap();
+
+    let stored: String = users::table.select(users::id).get_result(&mut conn).unwrap();
+    assert_eq!(stored, user.id.to_string());
+
+    let loaded: User = users::table
+        .filter(users::id.eq(&user.id))
+        .select(User::as_select())
+        .get_result(&mut conn)
+        .unwrap();
+    assert_eq!(loaded, user);
+}
+
+#[test]
+fn test_diesel_sqlite_invalid_value() {
+    let mut conn = connect();
+    diesel::sql_query("INSERT INTO users (id, name) VALUES ('not-a-suffix', 'Bad')")
+        .execute(&mut conn)
+        .unwrap();
+
+    let result = users::table.select(users::id).get_result::<TypeIdSuffix>(&mut conn);
+    assert!(result.is_err());
+
+    let raw: String = users::table.select(users::id).get_result(&mut conn).unwrap();
+    assert_eq!(raw, "not-a-suffix");
+}
@@ -0,0 +1,62 @@
+//! Differential tests comparing `TypeIdSuffix` with a naive reference encoder.
+//!
+//! The reference routine below walks the 130-bit big-endian stream (two zero
+//! padding bits followed by the 128 UUID bits) one bit at a time, collecting
+//! groups of five. It shares no code with the crate's encoder, so a bit-order
+//! or alphabet mistake in either one shows up as a mismatch.
+
+use std::str::FromStr;
+
+use proptest::prelude::*;
+use typeid_suffix::prelude::*;
+
+/// The `TypeID` alphabet, written out independently of the crate's table.
+const REFERENCE_ALPHABET: &str = "0123456789abcdefghjkmnpqrstvwxyz";
+
+/// Encodes a UUID one bit at a time, as described in the `TypeID` specification.
+fn reference_encode(uuid: Uuid) -> String {
+    let value = uuid.as_u128();
+    let bits: Vec<bool> = [false, false]
+        .into_iter()
+        .chain((0..128).rev().map(|bit| (value >> bit) & 1 == 1))
+        .collect();
+
+    bits.chunks(5)
+        .map(|group| {
+            let index = group.iter().fold(0, |index, &bit| (index << 1) | usize::from(bit));
+            REFERENCE_ALPHABET.as_bytes()[index] as char
+        })
+        .collect()
+}
+
+#[test]
+fn test_reference_encoder_matches_known_vectors() {
+    assert_eq!(reference_encode(Uuid::nil()), "00000000000000000000000000");
+    assert_eq!(reference_encode(Uuid::max()), "7zzzzzzzzzzzzzzzzzzzzzzzzz");
+    assert_eq!(
+        reference_encode(Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057").unwrap()),
+        "01h455vb4pex5vsknk084sn02q"
+    );
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10_000))]
+
+    #[test]
+    fn test_encoding_matches_reference(value in any::<u128>()) {
+        let uuid = Uuid::from_u128(value);
+        let expected = reference_encode(uuid);
+        let actual = TypeIdSuffix::from(uuid);
+        prop_assert_eq!(&*actual, expected.as_str(), "UUID {} encoded as {} but the reference gives {}", uuid, actual, expected);
+
+        // Decoding the reference encoding must give the UUID back, unless
+        // `strict-global` rejects the UUID itself
+        match TypeIdSuffix::from_str(&expected) {
+            Ok(decoded) => prop_assert_eq!(decoded.to_uuid(), uuid, "UUID {} from {}", uuid, expected),
+            Err(error) => prop_assert!(
+                cfg!(feature = "strict-global") && matches!(error, DecodeError::InvalidUuid(_)),
+                "UUID {} from {}: {}", uuid, expected, error
+            ),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Integration tests for the `instrument` feature of `TypeIdSuffix`.
+//!
+//! These tests install a small capturing subscriber and verify the span that
+//! `from_str` emits.
+
+#![cfg(feature = "instrument")]
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+use typeid_suffix::prelude::*;
+
+/// A span seen by `CapturingSubscriber`, with its recorded fields.
+#[derive(Debug)]
+struct CapturedSpan {
+    metadata: &'static Metadata<'static>,
+    fields: Vec<(String, String)>,
+}
+
+impl Visit for CapturedSpan {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.fields.push((field.name().to_string(), format!("{value:?}")));
+    }
+}
+
+/// Records every span created while it is the default subscriber.
+///
+/// Span IDs are indices into `spans` plus one.
+#[derive(Default)]
+struct CapturingSubscriber {
+    spans: Arc<Mutex<Vec<CapturedSpan>>>,
+}
+
+impl Subscriber for CapturingSubscriber {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+        let mut span = CapturedSpan { metadata: attributes.metadata(), fields: Vec::new() };
+        attributes.record(&mut span);
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(span);
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, id: &Id, values: &Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        values.record(&mut spans[usize::try_from(id.into_u64()).unwrap() - 1]);
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+fn capture_from_str(input: &str) -> (Result<TypeIdSuffix, DecodeError>, Vec<CapturedSpan>) {
+    let subscriber = CapturingSubscriber::default();
+    let spans = Arc::clone(&subscriber.spans);
+    let result = tracing::subscriber::with_default(subscriber, || TypeIdSuffix::from_str(input));
+    let spans = std::mem::take(&mut *spans.lock().unwrap());
+    (result, spans)
+}
+
+#[test]
+fn test_from_str_span_records_length_and_version() {
+    let (result, spans) = capture_from_str("01h455vb4pex5vsknk084sn02q");
+    assert!(result.is_ok());
+
+    let span = spans.iter().find(|span| span.metadata.name() == "from_str").expect("missing from_str span");
+    assert_eq!(*span.metadata.level(), Level::TRACE);
+    assert!(span.fields.contains(&("input_len".to_string(), "26".to_string())));
+    assert!(span.fields.contains(&("version".to_string(), "7".to_string())));
+}
+
+#[test]
+fn test_from_str_span_on_error_has_no_version() {
+    let (result, spans) = capture_from_str("too_short");
+    assert!(result.is_err());
+
+    let span = spans.iter().find(|span| span.metadata.name() == "from_str").expect("missing from_str span");
+    assert!(span.fields.contains(&("input_len".to_string(), "9".to_string())));
+    assert!(span.fields.iter().all(|(name, _)| name != "version"));
+}
@@ -0,0 +1,19 @@
+//! Specification test vectors for `TypeIdSuffix`, loaded from `valid.json`.
+//!
+//! These tests run every vector in the bundled `valid.json` through both
+//! decoding (`from_str`) and encoding (`from_uuid`).
+//!
+//! Several vectors (e.g. nil) are not RFC4122 UUIDs, so these tests are skipped
+//! when the non-compliant `strict-global` feature is enabled.
+
+#![cfg(not(feature = "strict-global"))]
+
+use std::str::FromStr;
+
+use serde_json::Value;
+use typeid_suffix::prelude::*;
+
+#[macro_use]
+mod macros;
+
+create_test_vectors!();
@@ -1,3 +1,7 @@
+/// Generates a `run_all_test_vectors` test over the bundled `valid.json`.
+///
+/// The invoking test crate must have `FromStr`, `serde_json::Value`, `Uuid`,
+/// and `TypeIdSuffix` in scope.
 #[macro_export]
 macro_rules! create_test_vectors {
     () => {
@@ -15,9 +19,9 @@ macro_rules! create_test_vectors {
             assert_eq!(Uuid::try_from(&decoded).unwrap(), uuid, "Decoding failed for '{}'", name);
 
             // Test encoding
-            let encoded = TypeIdSuffix::new(uuid)
+            let encoded = TypeIdSuffix::from_uuid(uuid)
                 .unwrap_or_else(|e| panic!("Failed to create `TypeId`suffix for '{}': {:?}", name, e));
-            assert_eq!(encoded.as_str(), suffix, "Encoding failed for '{}'", name);
+            assert_eq!(encoded.as_ref(), suffix, "Encoding failed for '{}'", name);
         }
 
         fn generate_tests() {
@@ -32,15 +36,9 @@ macro_rules! create_test_vectors {
             }
         }
 
-        // #[cfg(test)]
-        // mod tests {
-        //     use super::*;
-        //     use crate::create_test_vectors;
-        //
-        //     #[test]
-        //     fn run_all_test_vectors() {
-        //         create_test_vectors::generate_tests();
-        //     }
-        // }
+        #[test]
+        fn run_all_test_vectors() {
+            generate_tests();
+        }
     };
 }
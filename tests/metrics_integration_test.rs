@@ -0,0 +1,82 @@
+//! Integration tests for the `metrics` feature of `TypeIdSuffix`.
+//!
+//! These tests install a small recording backend and verify that parse
+//! failures increment the decode error counter with the right reason label.
+
+#![cfg(feature = "metrics")]
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use metrics::{Counter, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit};
+use typeid_suffix::prelude::*;
+
+/// Keeps one atomic per counter, keyed by name and `reason` label.
+#[derive(Default)]
+struct CountingRecorder {
+    counters: Mutex<HashMap<(String, String), Arc<AtomicU64>>>,
+}
+
+impl CountingRecorder {
+    fn count(&self, name: &str, reason: &str) -> u64 {
+        self.counters
+            .lock()
+            .unwrap()
+            .get(&(name.to_string(), reason.to_string()))
+            .map_or(0, |counter| counter.load(Ordering::Relaxed))
+    }
+}
+
+impl Recorder for CountingRecorder {
+    fn describe_counter(&self, _key: KeyName, _unit: Option<Unit>, _description: SharedString) {}
+
+    fn describe_gauge(&self, _key: KeyName, _unit: Option<Unit>, _description: SharedString) {}
+
+    fn describe_histogram(&self, _key: KeyName, _unit: Option<Unit>, _description: SharedString) {}
+
+    fn register_counter(&self, key: &Key, _metadata: &Metadata<'_>) -> Counter {
+        let reason = key.labels().find(|label| label.key() == "reason").map_or("", |label| label.value());
+        let counter = Arc::clone(
+            self.counters.lock().unwrap().entry((key.name().to_string(), reason.to_string())).or_default(),
+        );
+        Counter::from_arc(counter)
+    }
+
+    fn register_gauge(&self, _key: &Key, _metadata: &Metadata<'_>) -> Gauge {
+        Gauge::noop()
+    }
+
+    fn register_histogram(&self, _key: &Key, _metadata: &Metadata<'_>) -> Histogram {
+        Histogram::noop()
+    }
+}
+
+const COUNTER: &str = "typeid_suffix_decode_errors_total";
+
+#[test]
+fn test_decode_errors_are_counted_by_reason() {
+    let recorder = CountingRecorder::default();
+    metrics::with_local_recorder(&recorder, || {
+        assert!(TypeIdSuffix::from_str("too_short").is_err());
+        assert!(TypeIdSuffix::from_str("81h455vb4pex5vsknk084sn02q").is_err());
+        assert!(TypeIdSuffix::try_from("01h455vb4pex5vsknk084sn0!q".to_string()).is_err());
+        assert!(TypeIdSuffix::from_str("").is_err());
+    });
+
+    assert_eq!(recorder.count(COUNTER, "InvalidLength"), 2);
+    assert_eq!(recorder.count(COUNTER, "InvalidFirstCharacter"), 1);
+    assert_eq!(recorder.count(COUNTER, "InvalidCharacter"), 1);
+    assert_eq!(recorder.count(COUNTER, "NonCanonical"), 0);
+}
+
+#[test]
+fn test_successful_parse_is_not_counted() {
+    let recorder = CountingRecorder::default();
+    metrics::with_local_recorder(&recorder, || {
+        assert!(TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").is_ok());
+    });
+
+    assert!(recorder.counters.lock().unwrap().is_empty());
+}
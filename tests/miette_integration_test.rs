@@ -0,0 +1,47 @@
+//! Integration tests for miette functionality of `DecodeError`.
+//!
+//! These tests verify that every `DecodeError` variant reports a stable
+//! diagnostic code and a help message.
+
+#![cfg(feature = "miette")]
+
+use std::str::FromStr;
+
+use miette::Diagnostic;
+use typeid_suffix::prelude::*;
+
+fn code(error: &DecodeError) -> String {
+    error.code().unwrap().to_string()
+}
+
+#[test]
+fn test_miette_codes() {
+    let cases = [
+        (DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength), "typeid_suffix::invalid_length"),
+        (DecodeError::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter), "typeid_suffix::non_ascii_character"),
+        (DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter), "typeid_suffix::invalid_first_character"),
+        (DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidCharacter), "typeid_suffix::invalid_character"),
+        (DecodeError::InvalidSuffix(InvalidSuffixReason::NonCanonical), "typeid_suffix::non_canonical"),
+        (DecodeError::InvalidUuid(InvalidUuidReason::InvalidVersion), "typeid_suffix::invalid_version"),
+        (DecodeError::InvalidUuid(InvalidUuidReason::InvalidVariant), "typeid_suffix::invalid_variant"),
+        (DecodeError::InvalidUuid(InvalidUuidReason::InvalidBytes), "typeid_suffix::invalid_bytes"),
+        (DecodeError::InvalidUuid(InvalidUuidReason::GenerationUnavailable), "typeid_suffix::generation_unavailable"),
+        (DecodeError::InvalidUuid(InvalidUuidReason::NilNotAllowed), "typeid_suffix::nil_not_allowed"),
+        (DecodeError::InvalidPrefix(PrefixError::EmptyWithSeparator), "typeid_suffix::invalid_prefix"),
+    ];
+
+    for (error, expected) in cases {
+        assert_eq!(code(&error), expected);
+        assert!(error.help().is_some(), "missing help for {expected}");
+    }
+}
+
+#[test]
+fn test_miette_from_parse_error() {
+    let error = TypeIdSuffix::from_str("too_short").unwrap_err();
+    assert_eq!(code(&error), "typeid_suffix::invalid_length");
+    assert_eq!(
+        error.help().unwrap().to_string(),
+        "suffixes must be exactly 26 lowercase base32 characters"
+    );
+}
@@ -0,0 +1,57 @@
+//! Integration tests for postgres functionality of `TypeIdSuffix`.
+//!
+//! These tests verify that `TypeIdSuffix` round-trips through the Postgres
+//! binary wire format for both `UUID` and `TEXT` columns.
+
+#![cfg(feature = "postgres")]
+
+use std::str::FromStr;
+
+use bytes::BytesMut;
+use postgres_types::{FromSql, ToSql, Type};
+use typeid_suffix::prelude::*;
+
+#[test]
+fn test_postgres_uuid_column_roundtrip() {
+    let suffix = TypeIdSuffix::default();
+    let mut buf = BytesMut::new();
+
+    suffix.to_sql_checked(&Type::UUID, &mut buf).unwrap();
+    assert_eq!(&buf[..], suffix.to_uuid().as_bytes());
+
+    let decoded = TypeIdSuffix::from_sql(&Type::UUID, &buf).unwrap();
+    assert_eq!(decoded, suffix);
+}
+
+#[test]
+fn test_postgres_text_column_roundtrip() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+
+    for ty in [Type::TEXT, Type::VARCHAR] {
+        let mut buf = BytesMut::new();
+        suffix.to_sql_checked(&ty, &mut buf).unwrap();
+        assert_eq!(&buf[..], b"01h455vb4pex5vsknk084sn02q");
+
+        let decoded = TypeIdSuffix::from_sql(&ty, &buf).unwrap();
+        assert_eq!(decoded, suffix);
+    }
+}
+
+#[test]
+fn test_postgres_text_column_invalid() {
+    let result = TypeIdSuffix::from_sql(&Type::TEXT, b"invalid_suffix");
+    let err = result.unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<DecodeError>(),
+        Some(&DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))
+    );
+}
+
+#[test]
+fn test_postgres_accepts() {
+    assert!(<TypeIdSuffix as ToSql>::accepts(&Type::UUID));
+    assert!(<TypeIdSuffix as ToSql>::accepts(&Type::TEXT));
+    assert!(<TypeIdSuffix as FromSql>::accepts(&Type::VARCHAR));
+    assert!(!<TypeIdSuffix as ToSql>::accepts(&Type::INT4));
+    assert!(TypeIdSuffix::default().to_sql_checked(&Type::INT4, &mut BytesMut::new()).is_err());
+}
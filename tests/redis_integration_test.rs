@@ -0,0 +1,41 @@
+//! Integration tests for redis functionality of `TypeIdSuffix`.
+//!
+//! These tests verify that `TypeIdSuffix` is written as its string form and
+//! parsed back from Redis values, without requiring a running server.
+
+#![cfg(feature = "redis")]
+
+use std::str::FromStr;
+
+use redis::{ErrorKind, FromRedisValue, ToRedisArgs, Value};
+use typeid_suffix::prelude::*;
+
+#[test]
+fn test_redis_to_args() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(suffix.to_redis_args(), vec![b"01h455vb4pex5vsknk084sn02q".to_vec()]);
+}
+
+#[test]
+fn test_redis_from_value() {
+    let value = Value::Data(b"01h455vb4pex5vsknk084sn02q".to_vec());
+    let suffix = TypeIdSuffix::from_redis_value(&value).unwrap();
+    assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+}
+
+#[test]
+fn test_redis_roundtrip() {
+    let suffix = TypeIdSuffix::default();
+    let value = Value::Data(suffix.to_redis_args().remove(0));
+    assert_eq!(TypeIdSuffix::from_redis_value(&value).unwrap(), suffix);
+}
+
+#[test]
+fn test_redis_malformed_value() {
+    let value = Value::Data(b"not-a-valid-suffix".to_vec());
+    let err = TypeIdSuffix::from_redis_value(&value).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TypeError);
+
+    let err = TypeIdSuffix::from_redis_value(&Value::Nil).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TypeError);
+}
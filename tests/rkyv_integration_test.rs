@@ -0,0 +1,43 @@
+//! Integration tests for rkyv functionality of `TypeIdSuffix`.
+//!
+//! These tests verify that `TypeIdSuffix` can be archived, accessed in place,
+//! and that invalid archived bytes are rejected by validation.
+
+#![cfg(feature = "rkyv")]
+
+use rkyv::rancor::Error;
+use typeid_suffix::prelude::*;
+
+#[test]
+fn test_rkyv_access() {
+    let suffix = TypeIdSuffix::default();
+    let bytes = rkyv::to_bytes::<Error>(&suffix).unwrap();
+
+    // The archived form is the 26 encoded bytes
+    assert_eq!(&bytes[..], suffix.as_bytes());
+
+    let archived = rkyv::access::<ArchivedTypeIdSuffix, Error>(&bytes).unwrap();
+    assert_eq!(archived.as_str(), suffix.as_ref());
+}
+
+#[test]
+fn test_rkyv_deserialize() {
+    let suffix = TypeIdSuffix::new::<V4>();
+    let bytes = rkyv::to_bytes::<Error>(&suffix).unwrap();
+
+    let deserialized = rkyv::from_bytes::<TypeIdSuffix, Error>(&bytes).unwrap();
+    assert_eq!(suffix, deserialized);
+}
+
+#[test]
+fn test_rkyv_rejects_invalid_bytes() {
+    let suffix = TypeIdSuffix::default();
+
+    let mut bytes = rkyv::to_bytes::<Error>(&suffix).unwrap();
+    bytes[0] = b'8';
+    assert!(rkyv::access::<ArchivedTypeIdSuffix, Error>(&bytes).is_err());
+
+    let mut bytes = rkyv::to_bytes::<Error>(&suffix).unwrap();
+    bytes[10] = b'u';
+    assert!(rkyv::access::<ArchivedTypeIdSuffix, Error>(&bytes).is_err());
+}
@@ -0,0 +1,37 @@
+//! Integration tests for schemars functionality of `TypeIdSuffix`.
+//!
+//! These tests verify that the generated JSON schema describes `TypeIdSuffix`
+//! as a string constrained to exactly what `from_str` accepts.
+
+#![cfg(feature = "schemars")]
+
+use schemars::{schema_for, JsonSchema};
+use serde_json::Value;
+use typeid_suffix::prelude::*;
+
+#[test]
+fn test_schema_fields() {
+    let schema = schema_for!(TypeIdSuffix);
+
+    assert_eq!(schema.get("type").and_then(Value::as_str), Some("string"));
+    assert_eq!(
+        schema.get("pattern").and_then(Value::as_str),
+        Some("^[0-7][0-9a-hjkmnp-tv-z]{25}$")
+    );
+    assert_eq!(schema.get("minLength").and_then(Value::as_u64), Some(26));
+    assert_eq!(schema.get("maxLength").and_then(Value::as_u64), Some(26));
+}
+
+#[test]
+fn test_schema_in_struct() {
+    #[derive(JsonSchema)]
+    #[allow(dead_code)]
+    struct User {
+        id: TypeIdSuffix,
+    }
+
+    let schema = schema_for!(User);
+    let id = &schema.get("properties").unwrap()["id"];
+
+    assert_eq!(id["pattern"], "^[0-7][0-9a-hjkmnp-tv-z]{25}$");
+}
@@ -68,4 +68,155 @@ fn test_serde_with_different_formats() {
     let pretty_json = serde_json::to_string_pretty(&suffix).unwrap();
     let from_pretty_json: TypeIdSuffix = serde_json::from_str(&pretty_json).unwrap();
     assert_eq!(suffix, from_pretty_json);
-}
\ No newline at end of file
+}
+mod prefixed {
+    use std::str::FromStr;
+
+    use serde::{Deserialize, Serialize};
+    use typeid_suffix::prelude::*;
+    use typeid_suffix::serde_prefixed::{self, Prefix};
+
+    struct UserPrefix;
+
+    impl Prefix for UserPrefix {
+        const PREFIX: &'static str = "user";
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Account {
+        #[serde(
+            serialize_with = "serde_prefixed::serialize::<UserPrefix, _>",
+            deserialize_with = "serde_prefixed::deserialize::<UserPrefix, _>"
+        )]
+        id: TypeIdSuffix,
+        name: String,
+    }
+
+    #[test]
+    fn test_serde_prefixed_roundtrip() {
+        let account = Account {
+            id: TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap(),
+            name: "Test User".to_string(),
+        };
+
+        let json = serde_json::to_string(&account).unwrap();
+        assert_eq!(json, r#"{"id":"user_01h455vb4pex5vsknk084sn02q","name":"Test User"}"#);
+
+        let deserialized: Account = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, account);
+    }
+
+    #[test]
+    fn test_serde_prefixed_rejects_mismatched_prefix() {
+        let json = r#"{"id":"order_01h455vb4pex5vsknk084sn02q","name":"Test User"}"#;
+        let error = serde_json::from_str::<Account>(json).unwrap_err();
+        assert!(error.to_string().contains("expected prefix `user`, found `order`"));
+
+        let bare = r#"{"id":"01h455vb4pex5vsknk084sn02q","name":"Test User"}"#;
+        assert!(serde_json::from_str::<Account>(bare).is_err());
+
+        let invalid_suffix = r#"{"id":"user_81h455vb4pex5vsknk084sn02q","name":"Test User"}"#;
+        assert!(serde_json::from_str::<Account>(invalid_suffix).is_err());
+    }
+}
+
+mod typed {
+    use std::str::FromStr;
+
+    typeid_suffix::typed_id!(UserId, "user");
+
+    #[test]
+    fn test_serde_typed_id() {
+        let user = UserId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+        let json = serde_json::to_string(&user).unwrap();
+        assert_eq!(json, r#""user_01h455vb4pex5vsknk084sn02q""#);
+        assert_eq!(serde_json::from_str::<UserId>(&json).unwrap(), user);
+        assert!(serde_json::from_str::<UserId>(r#""order_01h455vb4pex5vsknk084sn02q""#).is_err());
+    }
+}
+
+#[test]
+fn test_deserialize_from_string_or_uuid_bytes() {
+    #[derive(Deserialize)]
+    struct Row(#[serde(deserialize_with = "typeid_suffix::serde_uuid_bytes::deserialize")] TypeIdSuffix);
+
+    let suffix: TypeIdSuffix = "01h455vb4pex5vsknk084sn02q".parse().unwrap();
+    let uuid = suffix.to_uuid();
+    let uuid_bytes: &[u8] = uuid.as_bytes();
+
+    // Human-readable: a JSON string and a JSON array of the 16 UUID bytes
+    let from_json_str: TypeIdSuffix = serde_json::from_str("\"01h455vb4pex5vsknk084sn02q\"").unwrap();
+    let from_json_bytes: TypeIdSuffix = serde_json::from_str(&serde_json::to_string(uuid_bytes).unwrap()).unwrap();
+    assert_eq!(from_json_str, suffix);
+    assert_eq!(from_json_bytes, suffix);
+
+    // Binary: the default impl reads the string written by `Serialize`, and the
+    // `serde_uuid_bytes` helper also accepts a 16-byte blob
+    let from_bincode_str: TypeIdSuffix = bincode::deserialize(&bincode::serialize(&suffix).unwrap()).unwrap();
+    let from_bincode_bytes: Row = bincode::deserialize(&bincode::serialize(uuid_bytes).unwrap()).unwrap();
+    let from_bincode_str_row: Row = bincode::deserialize(&bincode::serialize(&suffix).unwrap()).unwrap();
+    assert_eq!(from_bincode_str, suffix);
+    assert_eq!(from_bincode_bytes.0, suffix);
+    assert_eq!(from_bincode_str_row.0, suffix);
+
+    assert!(bincode::deserialize::<TypeIdSuffix>(&bincode::serialize(uuid_bytes).unwrap()).is_err());
+    assert!(bincode::deserialize::<Row>(&bincode::serialize(&uuid_bytes[..15]).unwrap()).is_err());
+    assert!(serde_json::from_str::<TypeIdSuffix>(&serde_json::to_string(&uuid_bytes[..15]).unwrap()).is_err());
+    assert!(serde_json::from_str::<TypeIdSuffix>("[0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]").is_err());
+    assert!(serde_json::from_str::<TypeIdSuffix>("42").is_err());
+}
+
+#[test]
+fn test_serde_cbor_roundtrip() {
+    let suffix: TypeIdSuffix = "01h455vb4pex5vsknk084sn02q".parse().unwrap();
+
+    // CBOR is not human-readable, and stores the suffix as a text string
+    let cbor = serde_cbor::to_vec(&suffix).unwrap();
+    assert_eq!(serde_cbor::from_slice::<TypeIdSuffix>(&cbor).unwrap(), suffix);
+
+    let user = User { id: suffix, name: "Test User".to_string(), email: "test@example.com".to_string() };
+    let cbor = serde_cbor::to_vec(&user).unwrap();
+    assert_eq!(serde_cbor::from_slice::<User>(&cbor).unwrap(), user);
+}
+
+#[test]
+fn test_serde_uuid_bytes_roundtrip() {
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Row {
+        #[serde(with = "typeid_suffix::serde_uuid_bytes")]
+        id: TypeIdSuffix,
+    }
+
+    let row = Row { id: TypeIdSuffix::new::<V7>() };
+
+    let cbor = serde_cbor::to_vec(&row).unwrap();
+    assert_eq!(serde_cbor::from_slice::<Row>(&cbor).unwrap(), row);
+
+    let json = serde_json::to_string(&row).unwrap();
+    assert_eq!(json, format!("{{\"id\":{}}}", serde_json::to_string(row.id.to_uuid().as_bytes()).unwrap()));
+    assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+}
+
+#[test]
+fn test_serde_as_map_key() {
+    let first: TypeIdSuffix = "01h455vb4pex5vsknk084sn02q".parse().unwrap();
+    let second: TypeIdSuffix = "01h455vb4pex5vsknk084sn02r".parse().unwrap();
+    let map = HashMap::from([(first.clone(), 1), (second.clone(), 2)]);
+
+    let json = serde_json::to_value(&map).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "01h455vb4pex5vsknk084sn02q": 1,
+            "01h455vb4pex5vsknk084sn02r": 2,
+        })
+    );
+
+    let deserialized: HashMap<TypeIdSuffix, i32> = serde_json::from_str(&serde_json::to_string(&map).unwrap()).unwrap();
+    assert_eq!(deserialized, map);
+    assert_eq!(deserialized[&first], 1);
+    assert_eq!(deserialized[&second], 2);
+
+    let invalid: Result<HashMap<TypeIdSuffix, i32>, _> = serde_json::from_str(r#"{"not-a-suffix": 1}"#);
+    assert!(invalid.is_err());
+}
@@ -3,6 +3,11 @@
 //! These tests verify that `TypeIdSuffix` correctly implements the `TypeID` specification
 //! by testing against known test vectors. Each test vector consists of a `TypeID` suffix
 //! and its corresponding UUID representation.
+//!
+//! Several vectors (e.g. nil) are not RFC4122 UUIDs, so these tests are skipped
+//! when the non-compliant `strict-global` feature is enabled.
+
+#![cfg(not(feature = "strict-global"))]
 
 use typeid_suffix::prelude::*;
 use uuid::Uuid;
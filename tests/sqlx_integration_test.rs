@@ -0,0 +1,80 @@
+//! Integration tests for sqlx functionality of `TypeIdSuffix`.
+//!
+//! These tests verify that `TypeIdSuffix` can be bound as a query parameter
+//! and read back from a text column using an in-memory `SQLite` database, and
+//! that the `Postgres` mapping uses the native text type.
+
+#![cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+
+#[cfg(feature = "sqlx-sqlite")]
+use sqlx::{Connection, Row, SqliteConnection};
+use typeid_suffix::prelude::*;
+
+#[cfg(feature = "sqlx-sqlite")]
+async fn connect() -> SqliteConnection {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE users (id TEXT PRIMARY KEY NOT NULL, name TEXT NOT NULL)")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+    conn
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+#[tokio::test]
+async fn test_sqlx_sqlite_roundtrip() {
+    let mut conn = connect().await;
+    let suffix = TypeIdSuffix::default();
+
+    sqlx::query("INSERT INTO users (id, name) VALUES (?, ?)")
+        .bind(&suffix)
+        .bind("Test User")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    let row = sqlx::query("SELECT id, name FROM users WHERE id = ?")
+        .bind(&suffix)
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    let id: TypeIdSuffix = row.get("id");
+    let name: String = row.get("name");
+
+    assert_eq!(id, suffix);
+    assert_eq!(name, "Test User");
+
+    // The suffix is stored in its string form
+    let raw: String = sqlx::query_scalar("SELECT id FROM users").fetch_one(&mut conn).await.unwrap();
+    assert_eq!(raw, suffix.to_string());
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+#[tokio::test]
+async fn test_sqlx_sqlite_decode_error() {
+    let mut conn = connect().await;
+
+    sqlx::query("INSERT INTO users (id, name) VALUES (?, ?)")
+        .bind("invalid_suffix")
+        .bind("Test User")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    let row = sqlx::query("SELECT id FROM users").fetch_one(&mut conn).await.unwrap();
+    let result = row.try_get::<TypeIdSuffix, _>("id");
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "sqlx-postgres")]
+#[test]
+fn test_sqlx_postgres_type_info() {
+    use sqlx::postgres::Postgres;
+    use sqlx::Type;
+
+    assert_eq!(
+        <TypeIdSuffix as Type<Postgres>>::type_info(),
+        <str as Type<Postgres>>::type_info()
+    );
+    assert!(<TypeIdSuffix as Type<Postgres>>::compatible(&<String as Type<Postgres>>::type_info()));
+}
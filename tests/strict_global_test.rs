@@ -0,0 +1,71 @@
+//! Tests for the `strict-global` feature of `TypeIdSuffix`.
+//!
+//! These tests verify that non-RFC4122 UUIDs are rejected by every parsing path
+//! when the feature is enabled and accepted when it is not.
+
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+use typeid_suffix::prelude::*;
+
+// A Microsoft-variant UUID, which is not RFC4122
+const MICROSOFT_VARIANT: &str = "0110c853-1d09-52d8-d73e-1194e95b5f19";
+
+#[cfg(feature = "strict-global")]
+#[test]
+fn test_strict_global_rejects_non_rfc4122() {
+    let suffix: TypeIdSuffix = Uuid::parse_str(MICROSOFT_VARIANT).unwrap().into();
+    assert_eq!(
+        TypeIdSuffix::from_str(&suffix),
+        Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVariant))
+    );
+    assert!(TypeIdSuffix::from_array(suffix.to_array()).is_err());
+
+    let nil: TypeIdSuffix = Uuid::nil().into();
+    assert_eq!(
+        TypeIdSuffix::from_str(&nil),
+        Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVariant))
+    );
+
+    // RFC4122 UUIDs are still accepted
+    let v7 = TypeIdSuffix::default();
+    assert_eq!(TypeIdSuffix::from_str(&v7), Ok(v7));
+}
+
+#[cfg(not(feature = "strict-global"))]
+#[test]
+fn test_permissive_accepts_non_rfc4122() {
+    let suffix: TypeIdSuffix = Uuid::parse_str(MICROSOFT_VARIANT).unwrap().into();
+    assert_eq!(TypeIdSuffix::from_str(&suffix), Ok(suffix.clone()));
+    assert_eq!(TypeIdSuffix::from_array(suffix.to_array()), Ok(suffix));
+
+    let nil: TypeIdSuffix = Uuid::nil().into();
+    assert_eq!(TypeIdSuffix::from_str(&nil), Ok(nil));
+}
+
+#[cfg(all(feature = "strict-global", feature = "borsh"))]
+#[test]
+fn test_strict_global_borsh_rejects_non_rfc4122() {
+    let uuid = Uuid::parse_str(MICROSOFT_VARIANT).unwrap();
+    let error = borsh::from_slice::<TypeIdSuffix>(uuid.as_bytes()).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+
+    let v7 = TypeIdSuffix::default();
+    assert_eq!(borsh::from_slice::<TypeIdSuffix>(v7.to_uuid().as_bytes()).unwrap(), v7);
+}
+
+#[cfg(all(feature = "strict-global", feature = "postgres"))]
+#[test]
+fn test_strict_global_postgres_uuid_column_rejects_non_rfc4122() {
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, ToSql, Type};
+
+    let mut buf = BytesMut::new();
+    Uuid::parse_str(MICROSOFT_VARIANT).unwrap().to_sql(&Type::UUID, &mut buf).unwrap();
+    let error = TypeIdSuffix::from_sql(&Type::UUID, &buf).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<DecodeError>(),
+        Some(&DecodeError::InvalidUuid(InvalidUuidReason::InvalidVariant))
+    );
+}
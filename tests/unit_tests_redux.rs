@@ -46,3 +46,1435 @@ fn test_invalid_first_character() {
     let invalid_suffix = "80000000000000000000000000";
     assert!(TypeIdSuffix::from_str(invalid_suffix).is_err());
 }
+
+#[test]
+fn test_color_hash() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let color = suffix.color_hash();
+
+    assert_eq!(color, suffix.color_hash());
+    assert!(color <= 0xFF_FFFF);
+
+    // The derivation is stable across releases
+    assert_eq!(color, 0x00e8_723f);
+    let nil = TypeIdSuffix::from(Uuid::nil());
+    assert_eq!(nil.color_hash(), 0x0069_196c);
+}
+
+#[test]
+fn test_from_str_lenient() {
+    let canonical = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+
+    let uppercase = TypeIdSuffix::from_str_lenient("01H455VB4PEX5VSKNK084SN02Q").unwrap();
+    assert_eq!(uppercase, canonical);
+    assert_eq!(uppercase.to_string(), "01h455vb4pex5vsknk084sn02q");
+
+    let ambiguous = TypeIdSuffix::from_str_lenient("O1h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(ambiguous, canonical);
+
+    let ambiguous = TypeIdSuffix::from_str_lenient("0Ih455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(ambiguous, canonical);
+
+    // Strict parsing is unchanged
+    assert!(TypeIdSuffix::from_str("01H455VB4PEX5VSKNK084SN02Q").is_err());
+    assert!(TypeIdSuffix::from_str_lenient("01h455vb4pex5vsknk084sn0u").is_err());
+}
+
+#[test]
+fn test_from_buffer_at() {
+    let record = b"\x01\x02user:01h455vb4pex5vsknk084sn02q\xff";
+    let suffix = TypeIdSuffix::from_buffer_at(record, 7).unwrap();
+    assert_eq!(suffix, TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap());
+
+    // Reading exactly up to the end of the buffer is in bounds
+    let exact = TypeIdSuffix::from_buffer_at(&record[..33], 7).unwrap();
+    assert_eq!(exact, suffix);
+
+    // Offsets that run past the end are rejected
+    assert_eq!(
+        TypeIdSuffix::from_buffer_at(record, 9),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))
+    );
+    assert_eq!(
+        TypeIdSuffix::from_buffer_at(record, usize::MAX),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))
+    );
+
+    // Bytes that are in bounds are still validated
+    assert_eq!(
+        TypeIdSuffix::from_buffer_at(record, 8),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter))
+    );
+}
+
+#[test]
+fn test_eq_ignore_case() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+
+    assert!(suffix.eq_ignore_case("01h455vb4pex5vsknk084sn02q"));
+    assert!(suffix.eq_ignore_case("01H455VB4PEX5VSKNK084SN02Q"));
+    assert!(suffix.eq_ignore_case("01h455VB4pex5vsknk084SN02q"));
+    assert!(!suffix.eq_ignore_case("00000000000000000000000000"));
+    assert!(!suffix.eq_ignore_case("01H455VB4PEX5VSKNK084SN02"));
+}
+
+#[test]
+fn test_hash_matches_str() {
+    use std::collections::{HashMap, HashSet};
+
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let mut map = HashMap::new();
+    map.insert(suffix.clone(), "value");
+
+    assert_eq!(map.get("01h455vb4pex5vsknk084sn02q"), Some(&"value"));
+    assert_eq!(map.get(&suffix), Some(&"value"));
+    assert_eq!(map.get("00000000000000000000000000"), None);
+
+    let set: HashSet<TypeIdSuffix> = std::iter::once(suffix).collect();
+    assert!(set.contains("01h455vb4pex5vsknk084sn02q"));
+}
+
+#[test]
+fn test_otel_ids() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let uuid = Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057").unwrap();
+
+    assert_eq!(&suffix.to_otel_trace_id(), uuid.as_bytes());
+    assert_eq!(suffix.to_otel_span_id(), [0x01, 0x89, 0x0a, 0x5d, 0xac, 0x96, 0x77, 0x4b]);
+    assert_eq!(suffix.to_otel_span_id(), uuid.as_bytes()[..8]);
+}
+
+#[test]
+fn test_ncs_and_future_variant_roundtrip() {
+    // NCS variant: the top bit of byte 8 is 0
+    let mut ncs_bytes = [0x5a_u8; 16];
+    ncs_bytes[6] = 0x0b; // Unrecognized version nibble
+    ncs_bytes[8] = 0x3c;
+    // Future variant: the top three bits of byte 8 are 111
+    let mut future_bytes = [0xa5_u8; 16];
+    future_bytes[6] = 0xc3; // Unrecognized version nibble
+    future_bytes[8] = 0xe7;
+
+    for (bytes, variant) in [(ncs_bytes, uuid::Variant::NCS), (future_bytes, uuid::Variant::Future)] {
+        let uuid = Uuid::from_bytes(bytes);
+        assert_eq!(uuid.get_variant(), variant);
+        assert_eq!(uuid.get_version(), None);
+
+        let suffix: TypeIdSuffix = uuid.into();
+        assert_eq!(suffix.to_uuid().as_bytes(), &bytes);
+
+        // Non-RFC4122 variants are accepted by `from_str`, as the spec encodes any 128-bit value
+        #[cfg(not(feature = "strict-global"))]
+        {
+            let parsed = TypeIdSuffix::from_str(&suffix).unwrap();
+            assert_eq!(parsed, suffix);
+            assert_eq!(parsed.to_uuid(), uuid);
+        }
+    }
+}
+
+#[test]
+fn test_to_typeid_string() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+
+    assert_eq!(suffix.to_typeid_string("user").unwrap(), "user_01h455vb4pex5vsknk084sn02q");
+    assert_eq!(suffix.to_typeid_string("pre_fix").unwrap(), "pre_fix_01h455vb4pex5vsknk084sn02q");
+    assert_eq!(suffix.to_typeid_string("").unwrap(), "01h455vb4pex5vsknk084sn02q");
+
+    assert_eq!(suffix.to_typeid_string("User"), Err(PrefixError::InvalidCharacter { position: 0 }));
+    assert_eq!(suffix.to_typeid_string("user1"), Err(PrefixError::InvalidCharacter { position: 4 }));
+    assert_eq!(suffix.to_typeid_string("_user"), Err(PrefixError::LeadingUnderscore));
+    assert_eq!(suffix.to_typeid_string("user_"), Err(PrefixError::TrailingUnderscore));
+    assert_eq!(suffix.to_typeid_string(&"a".repeat(64)), Err(PrefixError::TooLong));
+    assert!(suffix.to_typeid_string(&"a".repeat(63)).is_ok());
+}
+
+#[test]
+fn test_tagged_bytes_roundtrip() {
+    let suffixes = [
+        TypeIdSuffix::default(),
+        TypeIdSuffix::new::<V4>(),
+        TypeIdSuffix::from(Uuid::nil()),
+        TypeIdSuffix::from(Uuid::max()),
+    ];
+
+    for suffix in suffixes {
+        for tag in [0, 1, 42, 0x80, u8::MAX] {
+            let bytes = suffix.to_tagged_bytes(tag);
+            assert_eq!(bytes[0], tag);
+            assert_eq!(&bytes[1..], suffix.to_uuid().as_bytes());
+            assert_eq!(TypeIdSuffix::from_tagged_bytes(bytes), (tag, suffix.clone()));
+        }
+    }
+}
+
+#[test]
+fn test_from_array_validation() {
+    let mut bytes = *b"01h455vb4pex5vsknk084sn02q";
+    assert_eq!(TypeIdSuffix::from_array(bytes), TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q"));
+
+    bytes[0] = b'8';
+    assert_eq!(
+        TypeIdSuffix::from_array(bytes),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter))
+    );
+
+    let mut bytes = *b"01h455vb4pex5vsknk084sn02q";
+    bytes[5] = b'u';
+    assert_eq!(
+        TypeIdSuffix::from_array(bytes),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidCharacter))
+    );
+}
+
+#[test]
+fn test_version_char_index() {
+    const ALPHABET: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(suffix.to_uuid().get_version_num(), 7);
+
+    let version_char = suffix.as_bytes()[TypeIdSuffix::VERSION_CHAR_INDEX];
+    let value = ALPHABET.iter().position(|&c| c == version_char).unwrap();
+    assert_eq!((value >> TypeIdSuffix::VERSION_BIT_OFFSET) & 0xF, 7);
+
+    // Rewrite the version nibble to 4, keeping the low bit of the character
+    let low_bit = value & ((1 << TypeIdSuffix::VERSION_BIT_OFFSET) - 1);
+    let mut bytes = suffix.to_array();
+    bytes[TypeIdSuffix::VERSION_CHAR_INDEX] = ALPHABET[(4 << TypeIdSuffix::VERSION_BIT_OFFSET) | low_bit];
+
+    let modified = TypeIdSuffix::from_array(bytes).unwrap();
+    assert_eq!(modified.to_uuid().get_version(), Some(uuid::Version::Random));
+    assert_eq!(modified.to_uuid().get_version_num(), 4);
+}
+
+#[test]
+fn test_from_typeid_str() {
+    let (prefix, suffix) = TypeIdSuffix::from_typeid_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(prefix, None);
+    assert_eq!(suffix, TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap());
+
+    let (prefix, suffix) = TypeIdSuffix::from_typeid_str("prefix_01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(prefix, Some("prefix"));
+    assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+
+    #[cfg(not(feature = "strict-global"))]
+    {
+        let (prefix, suffix) = TypeIdSuffix::from_typeid_str("pre_fix_00000000000000000000000000").unwrap();
+        assert_eq!(prefix, Some("pre_fix"));
+        assert_eq!(suffix.to_uuid(), Uuid::nil());
+    }
+
+    assert_eq!(
+        TypeIdSuffix::from_typeid_str("prefix_01h455vb4pex5vsknk084sn02"),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))
+    );
+
+    // Prefixes follow the same rules as `validate_prefix`, and the separator needs one
+    for (input, expected) in [
+        ("_01h455vb4pex5vsknk084sn02q", PrefixError::EmptyWithSeparator),
+        ("User_01h455vb4pex5vsknk084sn02q", PrefixError::InvalidCharacter { position: 0 }),
+        ("1a_01h455vb4pex5vsknk084sn02q", PrefixError::InvalidCharacter { position: 0 }),
+        ("pre__01h455vb4pex5vsknk084sn02q", PrefixError::TrailingUnderscore),
+    ] {
+        assert_eq!(TypeIdSuffix::from_typeid_str(input), Err(DecodeError::InvalidPrefix(expected)), "{input}");
+    }
+}
+
+#[test]
+fn test_version_and_variant() {
+    let v7 = TypeIdSuffix::new::<V7>();
+    assert_eq!(v7.version(), Some(Version::SortRand));
+    assert_eq!(v7.variant(), uuid::Variant::RFC4122);
+
+    let v4 = TypeIdSuffix::new::<V4>();
+    assert_eq!(v4.version(), Some(Version::Random));
+    assert_eq!(v4.variant(), uuid::Variant::RFC4122);
+
+    let nil = TypeIdSuffix::from(Uuid::nil());
+    assert_eq!(nil.version(), Some(Version::Nil));
+}
+
+#[test]
+fn test_from_str_guarded() {
+    let expected = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+
+    assert_eq!(TypeIdSuffix::from_str_guarded("01h455vb4pex5vsknk084sn02q", 26), Ok(expected.clone()));
+    assert_eq!(TypeIdSuffix::from_str_guarded("user_01h455vb4pex5vsknk084sn02q", 90), Ok(expected));
+
+    // Over-long input is rejected by the guard
+    assert_eq!(
+        TypeIdSuffix::from_str_guarded("user_01h455vb4pex5vsknk084sn02q", 26),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))
+    );
+    let huge = format!("{}_01h455vb4pex5vsknk084sn02q", "a".repeat(1 << 20));
+    assert_eq!(
+        TypeIdSuffix::from_str_guarded(&huge, 90),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))
+    );
+
+    // Input within the guard is still validated
+    assert!(TypeIdSuffix::from_str_guarded("user_invalid", 90).is_err());
+}
+
+/// Characterizes `Ord` over a fixed mix of versions.
+///
+/// `Ord` used to decode to `Uuid` when both sides were v6/v7 and compare raw
+/// bytes otherwise. It now always compares the encoded bytes. The expected
+/// order below was recorded against the old implementation and is unchanged:
+/// the base32 encoding is fixed-width and big-endian, so byte order and UUID
+/// order agree for every pair, sortable or not.
+#[test]
+fn test_ord_characterization() {
+    let inputs = [
+        Uuid::max(),
+        Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057").unwrap(), // v7
+        Uuid::parse_str("1ec9414c-232a-6b00-b3c8-9e6bdeced846").unwrap(), // v6
+        Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(), // v4
+        Uuid::nil(),
+        Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8058").unwrap(), // v7
+        Uuid::parse_str("00000000-0000-1000-8000-000000000000").unwrap(), // v1
+    ];
+    let mut suffixes: Vec<TypeIdSuffix> = inputs.iter().copied().map(TypeIdSuffix::from).collect();
+    suffixes.sort();
+
+    let sorted: Vec<String> = suffixes.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        sorted,
+        [
+            "00000000000000000000000000",
+            "00000000002008000000000000",
+            "01h455vb4pex5vsknk084sn02q",
+            "01h455vb4pex5vsknk084sn02r",
+            "0ys50mr8sadc0b7j4ydffcxp26",
+            "2n1t201rmv87aae5j4csam8000",
+            "7zzzzzzzzzzzzzzzzzzzzzzzzz",
+        ]
+    );
+
+    let mut uuids = inputs;
+    uuids.sort();
+    assert_eq!(suffixes.iter().map(TypeIdSuffix::to_uuid).collect::<Vec<_>>(), uuids);
+}
+
+#[test]
+fn test_combine() {
+    let a = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let b = TypeIdSuffix::from(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap());
+
+    // Commutative
+    assert_eq!(a.combine(&b), b.combine(&a));
+
+    // Deterministic across calls and instances
+    let combined = a.combine(&b);
+    let (a2, b2) = (TypeIdSuffix::from_str(&a).unwrap(), TypeIdSuffix::from_str(&b).unwrap());
+    assert_eq!(combined, a2.combine(&b2));
+    assert_eq!(combined.version(), Some(Version::Random));
+    assert_eq!(combined.variant(), uuid::Variant::RFC4122);
+
+    // Distinct from both parents and from other pairs
+    assert_ne!(combined, a);
+    assert_ne!(combined, b);
+    assert_ne!(combined, a.combine(&a));
+    assert_ne!(combined, a.combine(&TypeIdSuffix::default()));
+}
+
+#[test]
+fn test_encode_decode_many() {
+    use typeid_suffix::batch::{decode_many, encode_many};
+
+    let uuids = vec![
+        Uuid::now_v7(),
+        Uuid::new_v4(),
+        Uuid::parse_str("1ec9414c-232a-6b00-b3c8-9e6bdeced846").unwrap(),
+        Uuid::nil(),
+        Uuid::max(),
+    ];
+    let suffixes = encode_many(&uuids);
+    assert_eq!(suffixes.len(), uuids.len());
+    for (suffix, uuid) in suffixes.iter().zip(&uuids) {
+        assert_eq!(*suffix, TypeIdSuffix::from(*uuid));
+    }
+    assert_eq!(decode_many(&suffixes), uuids);
+
+    assert!(encode_many(&[]).is_empty());
+    assert!(decode_many(&[]).is_empty());
+}
+
+#[test]
+fn test_to_uppercase_string() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let upper = suffix.to_uppercase_string();
+
+    assert_eq!(upper, "01H455VB4PEX5VSKNK084SN02Q");
+    // Canonical rendering is unaffected
+    assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+    // Strict parsing rejects the display form; lenient parsing restores the original
+    assert!(TypeIdSuffix::from_str(&upper).is_err());
+    assert_eq!(TypeIdSuffix::from_str_lenient(&upper), Ok(suffix));
+}
+
+#[test]
+fn test_range_for_millis() {
+    let at = |ms: u64, random: [u8; 10]| -> TypeIdSuffix {
+        uuid::Builder::from_unix_timestamp_millis(ms, &random).into_uuid().into()
+    };
+    let range = TypeIdSuffix::range_for_millis(1_700_000_000_000, 1_700_000_000_999);
+
+    assert_eq!(range.start().version(), Some(Version::SortRand));
+    assert_eq!(range.end().version(), Some(Version::SortRand));
+
+    // Inside the window, including both edges with extreme random bits
+    assert!(range.contains(&at(1_700_000_000_500, [0x5A; 10])));
+    assert!(range.contains(&at(1_700_000_000_000, [0x00; 10])));
+    assert!(range.contains(&at(1_700_000_000_999, [0xFF; 10])));
+
+    // Outside the window
+    assert!(!range.contains(&at(1_699_999_999_999, [0xFF; 10])));
+    assert!(!range.contains(&at(1_700_000_001_000, [0x00; 10])));
+
+    // A freshly generated suffix falls in a window around now
+    let now = TypeIdSuffix::new::<V7>();
+    let (secs, nanos) = now.to_uuid().get_timestamp().unwrap().to_unix();
+    let now_ms = secs * 1000 + u64::from(nanos) / 1_000_000;
+    assert!(TypeIdSuffix::range_for_millis(now_ms - 1000, now_ms + 1000).contains(&now));
+    assert!(!TypeIdSuffix::range_for_millis(0, now_ms - 1000).contains(&now));
+}
+
+#[test]
+#[should_panic(expected = "start_ms must not be greater than end_ms")]
+fn test_range_for_millis_reversed() {
+    let _ = TypeIdSuffix::range_for_millis(2, 1);
+}
+
+#[test]
+fn test_into_string_and_array() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+
+    let from_ref: String = (&suffix).into();
+    assert_eq!(from_ref, "01h455vb4pex5vsknk084sn02q");
+
+    let bytes: [u8; 26] = suffix.clone().into();
+    assert_eq!(&bytes, b"01h455vb4pex5vsknk084sn02q");
+    assert_eq!(bytes, suffix.to_array());
+
+    let owned: String = suffix.into();
+    assert_eq!(owned, from_ref);
+}
+
+#[test]
+fn test_timestamp() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    let close_to_now = |time: SystemTime| {
+        let now = SystemTime::now();
+        let delta = now.duration_since(time).unwrap_or_else(|e| e.duration());
+        delta < Duration::from_secs(5)
+    };
+
+    let v7: TypeIdSuffix = Uuid::now_v7().into();
+    let v7 = TypeIdSuffix::from_str(&v7).unwrap();
+    assert!(close_to_now(v7.timestamp().unwrap()));
+
+    let v6: TypeIdSuffix = Uuid::now_v6(&[1, 2, 3, 4, 5, 6]).into();
+    let v6 = TypeIdSuffix::from_str(&v6).unwrap();
+    assert!(close_to_now(v6.timestamp().unwrap()));
+
+    // Both layouts agree with the uuid crate's own timestamp decoding
+    for suffix in [&v6, &v7] {
+        let (secs, nanos) = suffix.to_uuid().get_timestamp().unwrap().to_unix();
+        let expected = UNIX_EPOCH + Duration::new(secs, nanos);
+        assert_eq!(suffix.timestamp(), Some(expected));
+    }
+
+    assert_eq!(TypeIdSuffix::new::<V4>().timestamp(), None);
+    assert_eq!(TypeIdSuffix::from(Uuid::nil()).timestamp(), None);
+}
+
+#[test]
+fn test_timestamp_max_v6() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    // Every timestamp bit set: 2^60 - 1 ticks since 1582, which is in the year 5236
+    let suffix = TypeIdSuffix::from(Uuid::parse_str("ffffffff-ffff-6fff-8fff-ffffffffffff").unwrap());
+    let expected = UNIX_EPOCH + Duration::new(103_072_857_660, 684_697_500);
+    assert_eq!(suffix.timestamp(), Some(expected));
+}
+
+#[test]
+fn test_try_from_string() {
+    let owned = String::from("01h455vb4pex5vsknk084sn02q");
+    let suffix = TypeIdSuffix::try_from(owned).unwrap();
+    assert_eq!(suffix, TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap());
+
+    for invalid in ["", "01h455vb4pex5vsknk084sn02", "81h455vb4pex5vsknk084sn02q", "01h455vb4pex5vsknk084sn02u"] {
+        assert_eq!(TypeIdSuffix::try_from(invalid.to_string()), TypeIdSuffix::from_str(invalid));
+        assert!(TypeIdSuffix::try_from(invalid.to_string()).is_err());
+    }
+}
+
+const WELL_KNOWN: TypeIdSuffix = TypeIdSuffix::from_str_const("01h455vb4pex5vsknk084sn02q");
+
+#[test]
+fn test_from_str_const() {
+    assert_eq!(WELL_KNOWN, TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap());
+    assert_eq!(WELL_KNOWN.version(), Some(Version::SortRand));
+}
+
+#[test]
+#[should_panic(expected = "First character of suffix must be '7' or less")]
+fn test_from_str_const_runtime_panic() {
+    let _ = TypeIdSuffix::from_str_const(std::hint::black_box("8zzzzzzzzzzzzzzzzzzzzzzzzz"));
+}
+
+#[test]
+fn test_as_cow_borrows() {
+    use std::borrow::Cow;
+
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let cow = suffix.as_cow();
+    match cow {
+        Cow::Borrowed(s) => {
+            assert_eq!(s, "01h455vb4pex5vsknk084sn02q");
+            assert_eq!(s.as_ptr(), suffix.as_ptr());
+        }
+        Cow::Owned(_) => panic!("as_cow must not allocate"),
+    }
+}
+
+#[test]
+fn test_from_str_canonical() {
+    let suffix = TypeIdSuffix::from_str_canonical("01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(suffix, TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap());
+
+    // The only way to smuggle extra bits into 26 characters is a first character
+    // above '7'; that is rejected before the canonical check is reached
+    assert_eq!(
+        TypeIdSuffix::from_str_canonical("8zzzzzzzzzzzzzzzzzzzzzzzzz"),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter))
+    );
+    assert_eq!(
+        TypeIdSuffix::from_str_canonical("01H455VB4PEX5VSKNK084SN02Q"),
+        TypeIdSuffix::from_str("01H455VB4PEX5VSKNK084SN02Q")
+    );
+}
+
+#[test]
+fn test_parse_registry() {
+    use typeid_suffix::registry::parse_registry;
+
+    let v7 = TypeIdSuffix::default().to_string();
+    let v4 = TypeIdSuffix::new::<V4>().to_string();
+    let entries = [
+        ("system", "01h455vb4pex5vsknk084sn02q"),
+        ("admin", v7.as_str()),
+        ("guest", v4.as_str()),
+    ];
+
+    let registry = parse_registry(entries).unwrap();
+    assert_eq!(registry.len(), 3);
+    assert_eq!(registry["system"].to_string(), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(registry["admin"].to_string(), v7);
+    assert_eq!(registry["guest"].to_string(), v4);
+
+    let with_invalid = [entries[0], entries[1], ("broken", "81h455vb4pex5vsknk084sn02q"), entries[2]];
+    let error = parse_registry(with_invalid).unwrap_err();
+    assert_eq!(error.key, "broken");
+    assert_eq!(error.error, DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter));
+    assert!(error.to_string().contains("broken"));
+}
+
+#[test]
+fn test_builder_strict_mode() {
+    // Version 4 bits, but the Microsoft variant
+    let microsoft = TypeIdSuffix::from(Uuid::parse_str("550e8400-e29b-41d4-c716-446655440000").unwrap());
+    assert_eq!(microsoft.variant(), uuid::Variant::Microsoft);
+
+    let lax = TypeIdSuffixBuilder::new();
+    let strict = TypeIdSuffixBuilder::new().strict(true);
+
+    #[cfg(not(feature = "strict-global"))]
+    assert_eq!(lax.parse(&microsoft), Ok(microsoft.clone()));
+    assert_eq!(
+        strict.parse(&microsoft),
+        Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVariant))
+    );
+
+    // RFC4122 variant, but no version
+    let nil = TypeIdSuffix::from(Uuid::nil());
+    assert!(strict.parse(&nil).is_err());
+
+    // Standard UUIDs pass in both modes
+    let v7 = TypeIdSuffix::default();
+    assert_eq!(lax.parse(&v7), Ok(v7.clone()));
+    assert_eq!(strict.parse(&v7), Ok(v7));
+    assert_eq!(TypeIdSuffixBuilder::default(), lax);
+}
+
+#[test]
+fn test_compare_with_uuid() {
+    let uuid = Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057").unwrap();
+    let suffix = TypeIdSuffix::from(uuid);
+    let smaller = Uuid::from_u128(uuid.as_u128() - 1);
+    let larger = Uuid::from_u128(uuid.as_u128() + 1);
+
+    assert_eq!(suffix.to_u128(), uuid.as_u128());
+    assert!(suffix == uuid);
+    assert!(uuid == suffix);
+    assert!(suffix != larger);
+    assert!(larger != suffix);
+
+    assert!(suffix > smaller);
+    assert!(suffix < larger);
+    assert!(smaller < suffix);
+    assert!(larger > suffix);
+    assert_eq!(suffix.partial_cmp(&uuid), Some(std::cmp::Ordering::Equal));
+}
+
+#[test]
+fn test_try_new() {
+    let v7 = TypeIdSuffix::try_new::<V7>().unwrap();
+    assert_eq!(v7.version(), Some(Version::SortRand));
+
+    let v4 = TypeIdSuffix::try_new::<V4>().unwrap();
+    assert_eq!(v4.version(), Some(Version::Random));
+
+    assert_eq!(TypeIdSuffix::try_new::<V5>(), Ok(TypeIdSuffix::new::<V5>()));
+    assert_eq!(TypeIdSuffix::try_new::<Nil>(), Ok(TypeIdSuffix::from(Uuid::nil())));
+}
+
+#[test]
+fn test_try_new_generation_unavailable() {
+    #[derive(Default)]
+    struct Unavailable(Uuid);
+
+    impl UuidVersion for Unavailable {
+        fn try_generate() -> Result<Uuid, InvalidUuidReason> {
+            Err(InvalidUuidReason::GenerationUnavailable)
+        }
+    }
+
+    impl std::ops::Deref for Unavailable {
+        type Target = Uuid;
+
+        fn deref(&self) -> &Uuid {
+            &self.0
+        }
+    }
+
+    assert_eq!(
+        TypeIdSuffix::try_new::<Unavailable>(),
+        Err(DecodeError::InvalidUuid(InvalidUuidReason::GenerationUnavailable))
+    );
+}
+
+#[test]
+fn test_from_uuid() {
+    let nil = TypeIdSuffix::from_uuid(Uuid::nil());
+    #[cfg(not(feature = "strict-global"))]
+    assert_eq!(nil.unwrap().to_string(), "00000000000000000000000000");
+    #[cfg(feature = "strict-global")]
+    assert!(nil.is_err());
+
+    let uuid = Uuid::now_v7();
+    assert_eq!(TypeIdSuffix::from_uuid(uuid), Ok(TypeIdSuffix::from(uuid)));
+}
+
+typeid_suffix::typed_id!(UserId, "user");
+typeid_suffix::typed_id!(OrderId, "order");
+typeid_suffix::typed_id!(BareId, "");
+
+#[test]
+fn test_typed_id() {
+    let user = UserId::from_str("user_01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(UserId::PREFIX, "user");
+    assert_eq!(user.to_string(), "user_01h455vb4pex5vsknk084sn02q");
+    assert_eq!(user.suffix().to_string(), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(UserId::from_str(&user.to_string()), Ok(user.clone()));
+
+    // A suffix converts explicitly into either type, but the types stay distinct
+    let suffix: TypeIdSuffix = user.into();
+    let order = OrderId::from(suffix);
+    assert_eq!(order.to_string(), "order_01h455vb4pex5vsknk084sn02q");
+
+    // Prefix validation
+    assert_eq!(
+        UserId::from_str("order_01h455vb4pex5vsknk084sn02q"),
+        Err(TypedIdError::PrefixMismatch { expected: "user", found: "order".to_string() })
+    );
+    assert_eq!(
+        UserId::from_str("01h455vb4pex5vsknk084sn02q"),
+        Err(TypedIdError::PrefixMismatch { expected: "user", found: String::new() })
+    );
+    assert_eq!(
+        UserId::from_str("user_81h455vb4pex5vsknk084sn02q"),
+        Err(TypedIdError::Decode(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter)))
+    );
+
+    // An empty prefix is written and read as the bare suffix
+    let bare = BareId::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(bare.to_string(), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(BareId::from_str(&bare.to_string()), Ok(bare));
+    assert_eq!(
+        BareId::from_str("_01h455vb4pex5vsknk084sn02q"),
+        Err(TypedIdError::Decode(DecodeError::InvalidPrefix(PrefixError::EmptyWithSeparator)))
+    );
+
+    assert_eq!(UserId::new().suffix().version(), Some(Version::SortRand));
+    assert_ne!(UserId::default(), UserId::default());
+}
+
+#[test]
+fn test_decode_lines() {
+    use std::io::Cursor;
+    use typeid_suffix::io::decode_lines;
+
+    let input = "01h455vb4pex5vsknk084sn02q\n\
+                 01h455vb4pex5vsknk084sn02r\r\n\
+                 \n\
+                 81h455vb4pex5vsknk084sn02q\n\
+                 7zzzzzzzzzzzzzzzzzzzzzzzzz";
+    let results: Vec<_> = decode_lines(Cursor::new(input)).collect();
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap().to_string(), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(results[1].as_ref().unwrap().to_string(), "01h455vb4pex5vsknk084sn02r");
+    assert!(matches!(
+        results[2],
+        Err(DecodeLinesError::Decode(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter)))
+    ));
+    assert_eq!(results[3].as_ref().ok(), TypeIdSuffix::from_str("7zzzzzzzzzzzzzzzzzzzzzzzzz").ok().as_ref());
+
+    // Malformed and non-UTF-8 lines yield errors without ending the stream
+    let bytes: &[u8] = b"short\n\xff\xfe\n01h455vb4pex5vsknk084sn02q\n";
+    let results: Vec<_> = decode_lines(bytes).collect();
+    assert_eq!(results.len(), 3);
+    assert!(matches!(
+        results[0],
+        Err(DecodeLinesError::Decode(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength)))
+    ));
+    assert!(matches!(results[1], Err(DecodeLinesError::Decode(_))));
+    assert!(results[2].is_ok());
+}
+
+#[test]
+fn test_decode_lines_reports_read_errors() {
+    use std::io::{self, BufReader, Read};
+    use typeid_suffix::io::decode_lines;
+
+    /// Returns one valid line, then fails every read.
+    struct FailingReader {
+        data: &'static [u8],
+    }
+
+    impl Read for FailingReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.data.is_empty() {
+                return Err(io::Error::other("disk unplugged"));
+            }
+            let len = self.data.len().min(buf.len());
+            buf[..len].copy_from_slice(&self.data[..len]);
+            self.data = &self.data[len..];
+            Ok(len)
+        }
+    }
+
+    let reader = BufReader::new(FailingReader { data: b"01h455vb4pex5vsknk084sn02q\n01h455vb4pex5vsknk084sn02r\n01h4" });
+    let results: Vec<_> = decode_lines(reader).collect();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().to_string(), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(results[1].as_ref().unwrap().to_string(), "01h455vb4pex5vsknk084sn02r");
+    match &results[2] {
+        Err(DecodeLinesError::Io(error)) => assert_eq!(error.to_string(), "disk unplugged"),
+        other => panic!("expected an I/O error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_parse_with_uuid() {
+    let (suffix, uuid) = TypeIdSuffix::parse_with_uuid("01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(uuid, suffix.to_uuid());
+    assert_eq!(suffix, TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap());
+
+    let generated = TypeIdSuffix::new::<V7>();
+    let (suffix, uuid) = TypeIdSuffix::parse_with_uuid(&generated).unwrap();
+    assert_eq!(suffix, generated);
+    assert_eq!(uuid, generated.to_uuid());
+
+    for input in ["", "01h455vb4pex5vsknk084sn02", "81h455vb4pex5vsknk084sn02q", "01h455vb4pex5vsknk084sn0uq"] {
+        assert_eq!(TypeIdSuffix::parse_with_uuid(input).map(|(suffix, _)| suffix), TypeIdSuffix::from_str(input));
+        assert!(TypeIdSuffix::parse_with_uuid(input).is_err());
+    }
+}
+
+#[test]
+fn test_checked_increment() {
+    let nil = TypeIdSuffix::from(Uuid::nil());
+    assert_eq!(nil.checked_increment().unwrap().to_string(), "00000000000000000000000001");
+
+    let max = TypeIdSuffix::from(Uuid::max());
+    assert_eq!(max.to_string(), "7zzzzzzzzzzzzzzzzzzzzzzzzz");
+    assert_eq!(max.checked_increment(), None);
+
+    // Carries propagate across characters
+    let suffix = TypeIdSuffix::from(Uuid::from_u128(0x1F));
+    assert_eq!(suffix.to_string(), "0000000000000000000000000z");
+    assert_eq!(suffix.checked_increment().unwrap().to_string(), "00000000000000000000000010");
+
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let next = suffix.checked_increment().unwrap();
+    assert!(next > suffix);
+    assert_eq!(next.to_string(), "01h455vb4pex5vsknk084sn02r");
+    assert_eq!(next.to_u128(), suffix.to_u128() + 1);
+}
+
+#[test]
+fn test_sort_by_timestamp() {
+    let at = |millis: u64| TypeIdSuffix::from(uuid::Builder::from_unix_timestamp_millis(millis, &[0; 10]).into_uuid());
+    let random: Vec<TypeIdSuffix> = (0..3).map(|_| TypeIdSuffix::new::<V4>()).collect();
+
+    let mut suffixes = Vec::from([
+        random[0].clone(),
+        at(3_000),
+        random[1].clone(),
+        at(1_000),
+        at(2_000),
+        random[2].clone(),
+    ]);
+    suffixes.sort_by_timestamp();
+
+    let millis: Vec<_> = suffixes.iter().map(TypeIdSuffix::timestamp_millis).collect();
+    assert_eq!(millis, [Some(1_000), Some(2_000), Some(3_000), None, None, None]);
+    assert_eq!(suffixes[3..], random[..]);
+}
+
+#[test]
+fn test_uuid_validation_reasons() {
+    let strict = TypeIdSuffixBuilder::new().strict(true);
+    let cases = [
+        // Version 4 with the NCS, Microsoft, and Future variants
+        ("550e8400-e29b-41d4-0716-446655440000", Err(InvalidUuidReason::InvalidVariant)),
+        ("550e8400-e29b-41d4-c716-446655440000", Err(InvalidUuidReason::InvalidVariant)),
+        ("550e8400-e29b-41d4-e716-446655440000", Err(InvalidUuidReason::InvalidVariant)),
+        // RFC4122 variant with version 0 and version 9
+        ("550e8400-e29b-01d4-a716-446655440000", Err(InvalidUuidReason::InvalidVersion)),
+        ("550e8400-e29b-91d4-a716-446655440000", Err(InvalidUuidReason::InvalidVersion)),
+        // RFC4122 variant with version 4
+        ("550e8400-e29b-41d4-a716-446655440000", Ok(())),
+    ];
+
+    for (uuid, expected) in cases {
+        let suffix = TypeIdSuffix::from(Uuid::parse_str(uuid).unwrap());
+        assert_eq!(
+            strict.parse(&suffix).map(|_| ()),
+            expected.map_err(DecodeError::InvalidUuid),
+            "{uuid}"
+        );
+        // The `TypeID` specification accepts every 128-bit value
+        #[cfg(not(feature = "strict-global"))]
+        {
+            assert_eq!(TypeIdSuffix::from_str(&suffix), Ok(suffix.clone()));
+            assert_eq!(TypeIdSuffix::from_uuid(suffix.to_uuid()), Ok(suffix));
+        }
+    }
+}
+
+#[test]
+fn test_from_os_str() {
+    use std::ffi::OsStr;
+
+    let suffix = TypeIdSuffix::from_os_str(OsStr::new("01h455vb4pex5vsknk084sn02q")).unwrap();
+    assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(
+        TypeIdSuffix::from_os_str(OsStr::new("81h455vb4pex5vsknk084sn02q")),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter))
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        let mut bytes = *b"01h455vb4pex5vsknk084sn02q";
+        bytes[5] = 0xFF;
+        assert_eq!(
+            TypeIdSuffix::from_os_str(OsStr::from_bytes(&bytes)),
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter))
+        );
+    }
+
+    #[cfg(windows)]
+    {
+        use std::ffi::OsString;
+        use std::os::windows::ffi::OsStringExt;
+
+        // An unpaired surrogate cannot be converted to UTF-8
+        let mut wide: Vec<u16> = "01h455vb4pex5vsknk084sn02q".encode_utf16().collect();
+        wide[5] = 0xD800;
+        assert_eq!(
+            TypeIdSuffix::from_os_str(&OsString::from_wide(&wide)),
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter))
+        );
+    }
+}
+
+#[test]
+fn test_inherent_len() {
+    // `str::len` is only reachable through `Deref`, which is not available in
+    // const contexts, so this only compiles with the inherent `const fn`
+    const SUFFIX: TypeIdSuffix = TypeIdSuffix::from_str_const("01h455vb4pex5vsknk084sn02q");
+    const LEN: usize = SUFFIX.len();
+    const EMPTY: bool = SUFFIX.is_empty();
+
+    assert_eq!(LEN, 26);
+    assert_eq!(EMPTY, TypeIdSuffix::default().is_empty());
+    assert_eq!(TypeIdSuffix::default().len(), 26);
+    assert_eq!(TypeIdSuffix::default().len(), TypeIdSuffix::default().to_string().len());
+}
+
+#[test]
+fn test_from_str_for_spec() {
+    let v7 = TypeIdSuffix::new::<V7>();
+    let v4 = TypeIdSuffix::new::<V4>();
+
+    assert_eq!(TypeIdSuffix::from_str_for_spec(&v7, SpecVersion::V0_2), Ok(v7.clone()));
+    assert_eq!(TypeIdSuffix::from_str_for_spec(&v7, SpecVersion::V0_3), Ok(v7));
+    assert_eq!(TypeIdSuffix::from_str_for_spec(&v4, SpecVersion::V0_3), Ok(v4.clone()));
+    assert_eq!(
+        TypeIdSuffix::from_str_for_spec(&v4, SpecVersion::V0_2),
+        Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVersion))
+    );
+
+    // Version 7 bits with the Microsoft variant are not a `UUIDv7`
+    let microsoft = TypeIdSuffix::from(Uuid::parse_str("01890a5d-ac96-774b-ccce-b302099a8057").unwrap());
+    assert_eq!(
+        TypeIdSuffix::from_str_for_spec(&microsoft, SpecVersion::V0_2),
+        Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVariant))
+    );
+    #[cfg(not(feature = "strict-global"))]
+    assert_eq!(TypeIdSuffix::from_str_for_spec(&microsoft, SpecVersion::V0_3), Ok(microsoft));
+
+    // Parsing errors are reported before the specification rules are applied
+    assert_eq!(
+        TypeIdSuffix::from_str_for_spec("81h455vb4pex5vsknk084sn02q", SpecVersion::V0_2),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter))
+    );
+    assert_eq!(SpecVersion::default(), SpecVersion::V0_3);
+}
+
+#[test]
+fn test_find_in() {
+    let (offset, suffix) = TypeIdSuffix::find_in("request id=user_01h455vb4pex5vsknk084sn02q done").unwrap();
+    assert_eq!(offset, 16);
+    assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+
+    // Whole input, start, and end positions
+    assert_eq!(TypeIdSuffix::find_in("01h455vb4pex5vsknk084sn02q").map(|(offset, _)| offset), Some(0));
+    assert_eq!(TypeIdSuffix::find_in("01h455vb4pex5vsknk084sn02q!").map(|(offset, _)| offset), Some(0));
+    assert_eq!(TypeIdSuffix::find_in("id: 01h455vb4pex5vsknk084sn02q").map(|(offset, _)| offset), Some(4));
+
+    // Windows inside longer words are skipped, and later matches are still found
+    assert_eq!(TypeIdSuffix::find_in("x01h455vb4pex5vsknk084sn02q"), None);
+    assert_eq!(TypeIdSuffix::find_in("01h455vb4pex5vsknk084sn02qq"), None);
+    let (offset, _) = TypeIdSuffix::find_in("81h455vb4pex5vsknk084sn02q then 01h455vb4pex5vsknk084sn02r").unwrap();
+    assert_eq!(offset, 32);
+
+    // Short, empty, and non-ASCII input
+    assert_eq!(TypeIdSuffix::find_in(""), None);
+    assert_eq!(TypeIdSuffix::find_in("01h455vb4pex5vsknk084sn02"), None);
+    assert_eq!(TypeIdSuffix::find_in("é01h455vb4pex5vsknk084sn02q").map(|(offset, _)| offset), Some(2));
+}
+
+#[test]
+fn test_from_uuid_ref() {
+    struct Record {
+        id: Uuid,
+    }
+
+    let record = Record { id: Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057").unwrap() };
+    let borrowed = TypeIdSuffix::from(&record.id);
+    assert_eq!(borrowed, TypeIdSuffix::from(record.id));
+    assert_eq!(borrowed.to_string(), "01h455vb4pex5vsknk084sn02q");
+
+    let uuids = [Uuid::nil(), Uuid::max(), Uuid::now_v7()];
+    let suffixes: Vec<TypeIdSuffix> = uuids.iter().map(TypeIdSuffix::from).collect();
+    assert_eq!(suffixes, uuids.map(TypeIdSuffix::from));
+}
+
+#[test]
+fn test_to_uuid_as() {
+    let v7 = TypeIdSuffix::new::<V7>();
+    assert_eq!(v7.to_uuid_as::<V7>(), Ok(v7.to_uuid()));
+    assert_eq!(v7.to_uuid_as::<V4>(), Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVersion)));
+
+    let v4 = TypeIdSuffix::new::<V4>();
+    assert_eq!(v4.to_uuid_as::<V4>(), Ok(v4.to_uuid()));
+    assert!(v4.to_uuid_as::<V7>().is_err());
+
+    assert!(TypeIdSuffix::new::<V1>().to_uuid_as::<V1>().is_ok());
+    assert!(TypeIdSuffix::new::<V6>().to_uuid_as::<V6>().is_ok());
+    assert!(TypeIdSuffix::from(Uuid::nil()).to_uuid_as::<Nil>().is_ok());
+    assert!(TypeIdSuffix::from(Uuid::max()).to_uuid_as::<Nil>().is_err());
+}
+
+#[test]
+fn test_display_alternate_uppercase() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(format!("{suffix}"), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(format!("{suffix:#}"), "01H455VB4PEX5VSKNK084SN02Q");
+    assert_eq!(format!("{suffix:#}"), suffix.to_uppercase_string());
+    assert_eq!(suffix.to_string(), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(format!("id={suffix:#}"), "id=01H455VB4PEX5VSKNK084SN02Q");
+}
+
+#[test]
+fn test_to_uuid_string() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(suffix.to_uuid_string(), "01890a5d-ac96-774b-bcce-b302099a8057");
+    assert_eq!(suffix.to_uuid_string(), suffix.to_uuid().hyphenated().to_string());
+    assert_eq!(suffix.to_uuid_simple_string(), "01890a5dac96774bbcceb302099a8057");
+    assert_eq!(suffix.to_uuid_simple_string(), suffix.to_uuid().simple().to_string());
+
+    let generated = TypeIdSuffix::new::<V4>();
+    assert_eq!(Uuid::parse_str(&generated.to_uuid_string()), Ok(generated.to_uuid()));
+}
+
+#[test]
+fn test_versioned_uuid_newtypes() {
+    fn created_at(id: V7Uuid) -> Option<(u64, u32)> {
+        id.get_timestamp().map(|timestamp| timestamp.to_unix())
+    }
+
+    let v7 = TypeIdSuffix::new::<V7>();
+    let uuid = V7Uuid::try_from(&v7).unwrap();
+    assert_eq!(*uuid, v7.to_uuid());
+    assert_eq!(Uuid::from(uuid), v7.to_uuid());
+    assert!(created_at(uuid).is_some());
+
+    let v4 = TypeIdSuffix::new::<V4>();
+    assert_eq!(V7Uuid::try_from(&v4), Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidVersion)));
+    assert_eq!(V4Uuid::try_from(&v4).map(Uuid::from), Ok(v4.to_uuid()));
+    assert!(V4Uuid::try_from(&v7).is_err());
+}
+
+#[test]
+fn test_from_chars() {
+    let suffix = TypeIdSuffix::from_chars("01h455vb4pex5vsknk084sn02q".chars()).unwrap();
+    assert_eq!(suffix, TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap());
+
+    assert_eq!(
+        TypeIdSuffix::from_chars("01h455vb4pex5vsknk084sn02".chars()),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))
+    );
+    assert_eq!(
+        TypeIdSuffix::from_chars(std::iter::empty()),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))
+    );
+    assert_eq!(
+        TypeIdSuffix::from_chars("01h455vb4pex5vsknk084sn02qq".chars()),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))
+    );
+    assert_eq!(
+        TypeIdSuffix::from_chars("01h455vb4pexé5vsknk084sn02q".chars()),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter))
+    );
+    assert_eq!(
+        TypeIdSuffix::from_chars("81h455vb4pex5vsknk084sn02q".chars()),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter))
+    );
+
+    // Only 27 characters are read from a longer stream
+    let mut stream = "01h455vb4pex5vsknk084sn02q and more".chars();
+    assert!(TypeIdSuffix::from_chars(stream.by_ref()).is_err());
+    assert_eq!(stream.as_str(), "and more");
+}
+
+#[test]
+fn test_from_uuids_dedup() {
+    use typeid_suffix::batch::from_uuids_dedup;
+
+    let uuids: Vec<Uuid> = (1..=3).map(Uuid::from_u128).collect();
+    let input = [uuids[1], uuids[0], uuids[1], uuids[2], uuids[0], uuids[2]];
+    let suffixes = from_uuids_dedup(input);
+
+    assert_eq!(
+        suffixes,
+        [TypeIdSuffix::from(uuids[1]), TypeIdSuffix::from(uuids[0]), TypeIdSuffix::from(uuids[2])]
+    );
+    assert!(from_uuids_dedup(std::iter::empty()).is_empty());
+}
+
+#[test]
+fn test_error_messages_unchanged() {
+    use std::error::Error;
+
+    let suffix_reasons = [
+        (InvalidSuffixReason::InvalidLength, "Suffix must be exactly 26 characters long"),
+        (InvalidSuffixReason::NonAsciiCharacter, "Suffix contains non-ASCII characters"),
+        (InvalidSuffixReason::InvalidFirstCharacter, "First character of suffix must be '7' or less"),
+        (InvalidSuffixReason::InvalidCharacter, "Suffix contains characters not in the base32 alphabet"),
+        (InvalidSuffixReason::NonCanonical, "Suffix is not in canonical form"),
+    ];
+    for (reason, message) in suffix_reasons {
+        assert_eq!(reason.to_string(), message);
+        assert_eq!(
+            DecodeError::InvalidSuffix(reason).to_string(),
+            format!("Invalid `TypeID` suffix: {message}")
+        );
+    }
+
+    let uuid_reasons = [
+        (InvalidUuidReason::InvalidVersion, "UUID version is not valid for this TypeID"),
+        (InvalidUuidReason::InvalidVariant, "UUID variant is not RFC4122"),
+        (InvalidUuidReason::InvalidBytes, "UUID bytes are invalid"),
+        (InvalidUuidReason::GenerationUnavailable, "UUID generation is unavailable in this environment"),
+        (InvalidUuidReason::NilNotAllowed, "UUID must not be nil"),
+    ];
+    for (reason, message) in uuid_reasons {
+        assert_eq!(reason.to_string(), message);
+        assert_eq!(DecodeError::InvalidUuid(reason).to_string(), format!("Invalid UUID: {message}"));
+    }
+
+    let prefix_errors = [
+        (
+            PrefixError::InvalidCharacter { position: 3 },
+            "Prefix must contain only lowercase ASCII letters and underscores (found an invalid character at position 3)",
+        ),
+        (PrefixError::LeadingUnderscore, "Prefix must not start with an underscore"),
+        (PrefixError::TrailingUnderscore, "Prefix must not end with an underscore"),
+        (PrefixError::TooLong, "Prefix must be at most 63 characters long"),
+        (PrefixError::EmptyWithSeparator, "Prefix must not be empty when followed by an underscore separator"),
+    ];
+    for (error, message) in prefix_errors {
+        assert_eq!(error.to_string(), message);
+        assert_eq!(DecodeError::InvalidPrefix(error).to_string(), format!("Invalid `TypeID` prefix: {message}"));
+    }
+
+    let decode = DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength);
+    let typed = TypedIdError::from(decode.clone());
+    assert_eq!(typed.to_string(), "Invalid `TypeID` suffix: Suffix must be exactly 26 characters long");
+    assert_eq!(typed.source().map(ToString::to_string), Some(decode.to_string()));
+    let mismatch = TypedIdError::PrefixMismatch { expected: "user", found: "post".to_string() };
+    assert_eq!(mismatch.to_string(), "Expected prefix `user`, found `post`");
+    assert!(mismatch.source().is_none());
+}
+
+#[test]
+fn test_write_to() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let mut buffer = Vec::new();
+    suffix.write_to(&mut buffer).unwrap();
+    suffix.write_to(&mut buffer).unwrap();
+    assert_eq!(buffer, b"01h455vb4pex5vsknk084sn02q01h455vb4pex5vsknk084sn02q");
+}
+
+#[test]
+fn test_hex_formatting() {
+    let nil = TypeIdSuffix::from(Uuid::nil());
+    assert_eq!(format!("{nil:x}"), "0".repeat(32));
+    assert_eq!(format!("{nil:X}"), "0".repeat(32));
+
+    let max = TypeIdSuffix::from(Uuid::max());
+    assert_eq!(format!("{max:x}"), "f".repeat(32));
+    assert_eq!(format!("{max:X}"), "F".repeat(32));
+
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(format!("{suffix:x}"), suffix.to_uuid().simple().to_string());
+}
+
+#[test]
+fn test_partition_by_version() {
+    use typeid_suffix::batch::partition_by_version;
+
+    let v4: Vec<TypeIdSuffix> = (0..3).map(|_| TypeIdSuffix::new::<V4>()).collect();
+    let v7: Vec<TypeIdSuffix> = (0..5).map(|_| TypeIdSuffix::new::<V7>()).collect();
+    let mixed: Vec<TypeIdSuffix> = v7.iter().chain(&v4).cloned().collect();
+
+    let groups = partition_by_version(&mixed);
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[&4], v4);
+    assert_eq!(groups[&7], v7);
+    assert!(partition_by_version(&[]).is_empty());
+}
+
+#[test]
+#[cfg(not(feature = "strict-global"))]
+fn test_from_str_non_nil() {
+    let nil = "00000000000000000000000000";
+    assert!(TypeIdSuffix::from_str(nil).is_ok());
+    assert_eq!(
+        TypeIdSuffix::from_str_non_nil(nil),
+        Err(DecodeError::InvalidUuid(InvalidUuidReason::NilNotAllowed))
+    );
+
+    let input = "01h455vb4pex5vsknk084sn02q";
+    assert_eq!(TypeIdSuffix::from_str_non_nil(input), TypeIdSuffix::from_str(input));
+    assert_eq!(
+        TypeIdSuffix::from_str_non_nil("0000000000000000000000000"),
+        Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength))
+    );
+}
+
+#[test]
+fn test_validate_prefix() {
+    use typeid_suffix::prefix::validate_prefix;
+
+    assert_eq!(validate_prefix(""), Ok(()));
+    assert_eq!(validate_prefix("pre_fix"), Ok(()));
+    assert_eq!(validate_prefix("a_b_c"), Ok(()));
+    assert_eq!(validate_prefix(&"a".repeat(63)), Ok(()));
+
+    assert_eq!(validate_prefix(&"a".repeat(64)), Err(PrefixError::TooLong));
+    assert_eq!(validate_prefix("PREFIX"), Err(PrefixError::InvalidCharacter { position: 0 }));
+    assert_eq!(validate_prefix("prefix1"), Err(PrefixError::InvalidCharacter { position: 6 }));
+    assert_eq!(validate_prefix("pre.fix"), Err(PrefixError::InvalidCharacter { position: 3 }));
+    assert_eq!(validate_prefix("pr\u{e9}fix"), Err(PrefixError::InvalidCharacter { position: 2 }));
+    assert_eq!(validate_prefix("  prefix"), Err(PrefixError::InvalidCharacter { position: 0 }));
+    assert_eq!(validate_prefix("_prefix"), Err(PrefixError::LeadingUnderscore));
+    assert_eq!(validate_prefix("prefix_"), Err(PrefixError::TrailingUnderscore));
+    assert_eq!(validate_prefix("_"), Err(PrefixError::LeadingUnderscore));
+}
+
+#[test]
+#[allow(unsafe_code)]
+fn test_from_array_unchecked() {
+    let input = "01h455vb4pex5vsknk084sn02q";
+    let bytes: [u8; 26] = input.as_bytes().try_into().unwrap();
+    // SAFETY: `input` is a valid suffix
+    let suffix = unsafe { TypeIdSuffix::from_array_unchecked(bytes) };
+    assert_eq!(suffix, TypeIdSuffix::from_str(input).unwrap());
+    assert_eq!(suffix.to_string(), input);
+}
+
+#[test]
+fn test_validate_many() {
+    use typeid_suffix::batch::validate_many;
+
+    let results = validate_many(&[
+        "01h455vb4pex5vsknk084sn02q",
+        "",
+        "81h455vb4pex5vsknk084sn02q",
+        "01h455vb4pex5vsknk084sn02q",
+        "01h455vb4pex5vsknk084sn0!q",
+    ]);
+    assert_eq!(
+        results,
+        [
+            Ok(()),
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength)),
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter)),
+            Ok(()),
+            Err(DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidCharacter)),
+        ]
+    );
+    assert!(validate_many(&[]).is_empty());
+}
+
+#[test]
+fn test_fmt_into() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+
+    let mut string = String::from("id=");
+    suffix.fmt_into(&mut string).unwrap();
+    assert_eq!(string, "id=01h455vb4pex5vsknk084sn02q");
+
+    let mut fixed: heapless::String<32> = heapless::String::new();
+    suffix.fmt_into(&mut fixed).unwrap();
+    assert_eq!(fixed.as_str(), "01h455vb4pex5vsknk084sn02q");
+
+    // A second suffix does not fit in the remaining capacity
+    assert!(suffix.fmt_into(&mut fixed).is_err());
+}
+
+#[test]
+fn test_cmp_by_bytes_matches_string_order() {
+    let suffixes = [
+        TypeIdSuffix::from(Uuid::nil()),
+        TypeIdSuffix::from(Uuid::max()),
+        TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap(),
+        TypeIdSuffix::new::<V4>(),
+        TypeIdSuffix::new::<V7>(),
+    ];
+    for a in &suffixes {
+        for b in &suffixes {
+            let (sa, sb): (&str, &str) = (a, b);
+            assert_eq!(a.cmp_by_bytes(b), sa.cmp(sb));
+            assert_eq!(a.cmp_by_bytes(b), a.cmp(b));
+        }
+    }
+}
+
+#[test]
+fn test_as_str_contents() {
+    let cases = [
+        (TypeIdSuffix::from(Uuid::nil()), "00000000000000000000000000"),
+        (TypeIdSuffix::from(Uuid::max()), "7zzzzzzzzzzzzzzzzzzzzzzzzz"),
+        (TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap(), "01h455vb4pex5vsknk084sn02q"),
+    ];
+    for (suffix, input) in cases {
+        let s: &str = &suffix;
+        assert_eq!(s, input);
+        assert_eq!(String::from(&suffix), input);
+    }
+}
+
+#[test]
+fn test_decode_error_into_boxed() {
+    const fn assert_send_sync_static<T: Send + Sync + 'static>() {}
+    assert_send_sync_static::<DecodeError>();
+
+    let errors = [
+        DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidLength),
+        DecodeError::InvalidSuffix(InvalidSuffixReason::NonAsciiCharacter),
+        DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidFirstCharacter),
+        DecodeError::InvalidSuffix(InvalidSuffixReason::InvalidCharacter),
+        DecodeError::InvalidSuffix(InvalidSuffixReason::NonCanonical),
+        DecodeError::InvalidUuid(InvalidUuidReason::InvalidVersion),
+        DecodeError::InvalidUuid(InvalidUuidReason::InvalidVariant),
+        DecodeError::InvalidUuid(InvalidUuidReason::InvalidBytes),
+        DecodeError::InvalidUuid(InvalidUuidReason::GenerationUnavailable),
+        DecodeError::InvalidUuid(InvalidUuidReason::NilNotAllowed),
+    ];
+    for error in errors {
+        let boxed = error.clone().into_boxed();
+        assert_eq!(boxed.to_string(), error.to_string());
+        assert_eq!(boxed.downcast_ref::<DecodeError>(), Some(&error));
+    }
+}
+
+#[test]
+fn test_from_byte_array() {
+    let bytes = [
+        0x01, 0x89, 0x0a, 0x5d, 0xac, 0x96, 0x77, 0x4b, 0xbc, 0xce, 0xb3, 0x02, 0x09, 0x9a, 0x80, 0x57,
+    ];
+    assert_eq!(TypeIdSuffix::from(bytes), TypeIdSuffix::from(Uuid::from_bytes(bytes)));
+    assert_eq!(TypeIdSuffix::from(bytes).to_string(), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(TypeIdSuffix::from([0; 16]), TypeIdSuffix::from(Uuid::nil()));
+    assert_eq!(TypeIdSuffix::from([0xff; 16]), TypeIdSuffix::from(Uuid::max()));
+}
+
+#[test]
+fn test_generate_batch() {
+    use typeid_suffix::batch::generate_batch;
+
+    let batch = generate_batch(1000);
+    assert_eq!(batch.len(), 1000);
+    assert!(batch.windows(2).all(|pair| pair[0] < pair[1]));
+    for suffix in &batch {
+        assert_eq!(suffix.version(), Some(uuid::Version::SortRand));
+        assert_eq!(TypeIdSuffix::from_str(suffix).as_ref(), Ok(suffix));
+    }
+    assert!(generate_batch(0).is_empty());
+}
+
+#[test]
+fn test_eq_bytes() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+    let matching: [u8; 26] = *b"01h455vb4pex5vsknk084sn02q";
+    let different: [u8; 26] = *b"01h455vb4pex5vsknk084sn02r";
+
+    assert!(suffix == matching);
+    assert!(matching == suffix);
+    assert!(suffix != different);
+    assert!(different != suffix);
+
+    let buffer: &[u8] = b"01h455vb4pex5vsknk084sn02q";
+    assert!(suffix == *buffer);
+    assert!(*buffer == suffix);
+    assert!(suffix != *b"01h455vb4pex5vsknk084sn02r".as_slice());
+
+    // Prefixes and extensions of the encoding are different lengths, so never equal
+    assert!(suffix != buffer[..25]);
+    assert!(buffer[..25] != suffix);
+    assert!(suffix != *b"01h455vb4pex5vsknk084sn02q0".as_slice());
+    assert!(suffix != *b"".as_slice());
+}
+
+#[test]
+fn test_time_range_bounds() {
+    use std::collections::BTreeMap;
+
+    let at = |ms: u64, random: u8| TypeIdSuffix::from(uuid::Builder::from_unix_timestamp_millis(ms, &[random; 10]).into_uuid());
+
+    let mut map = BTreeMap::new();
+    for ms in [999, 1_000, 1_500, 2_000, 2_001] {
+        for random in [0x00, 0x42, 0xFF] {
+            map.insert(at(ms, random), ms);
+        }
+    }
+
+    let found: Vec<u64> = map.range(TypeIdSuffix::time_range_bounds(1_000, 2_000)).map(|(_, &ms)| ms).collect();
+    assert_eq!(found, [1_000, 1_000, 1_000, 1_500, 1_500, 1_500, 2_000, 2_000, 2_000]);
+
+    let single: Vec<u64> = map.range(TypeIdSuffix::time_range_bounds(1_500, 1_500)).map(|(_, &ms)| ms).collect();
+    assert_eq!(single, [1_500; 3]);
+    assert_eq!(map.range(TypeIdSuffix::time_range_bounds(1_600, 1_900)).count(), 0);
+}
+
+#[test]
+fn test_from_uuid_const() {
+    const BYTES: [u8; 16] = [
+        0x01, 0x89, 0x0a, 0x5d, 0xac, 0x96, 0x77, 0x4b, 0xbc, 0xce, 0xb3, 0x02, 0x09, 0x9a, 0x80, 0x57,
+    ];
+    const SYSTEM_ID: TypeIdSuffix = TypeIdSuffix::from_uuid_const(BYTES);
+    const MAX_ID: TypeIdSuffix = TypeIdSuffix::from_uuid_const([0xFF; 16]);
+
+    assert_eq!(SYSTEM_ID, TypeIdSuffix::from(BYTES));
+    assert_eq!(SYSTEM_ID.to_string(), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(MAX_ID, TypeIdSuffix::from(Uuid::max()));
+}
+
+#[test]
+fn test_max_of_and_min_of() {
+    use typeid_suffix::batch::{max_of, min_of};
+
+    let suffixes: Vec<TypeIdSuffix> = [
+        "01h455vb4pex5vsknk084sn02q",
+        "01h455vb4pex5vsknk084sn02r",
+        "01h455vb4pex5vsknk084sn02p",
+        "01h455vb4pex5vsknk084sn02s",
+    ]
+    .iter()
+    .map(|s| TypeIdSuffix::from_str(s).unwrap())
+    .collect();
+
+    assert_eq!(max_of(&suffixes), Some(&suffixes[3]));
+    assert_eq!(min_of(&suffixes), Some(&suffixes[2]));
+    assert_eq!(max_of(&suffixes[..1]), Some(&suffixes[0]));
+    assert_eq!(min_of(suffixes.iter().take(2)), Some(&suffixes[0]));
+    assert_eq!(max_of(&[]), None);
+    assert_eq!(min_of(&[]), None);
+}
+
+#[test]
+fn test_proto_bytes_roundtrip() {
+    let uuid = Uuid::now_v7();
+    let suffix = TypeIdSuffix::from_proto_bytes(uuid.as_bytes()).unwrap();
+    assert_eq!(suffix.to_uuid(), uuid);
+    assert_eq!(suffix.to_proto_bytes(), *uuid.as_bytes());
+    assert_eq!(TypeIdSuffix::from_proto_bytes(&suffix.to_proto_bytes()), Ok(suffix));
+
+    for len in [0, 15, 17, 26] {
+        assert_eq!(
+            TypeIdSuffix::from_proto_bytes(&vec![0x01; len]),
+            Err(DecodeError::InvalidUuid(InvalidUuidReason::InvalidBytes))
+        );
+    }
+}
+
+#[test]
+fn test_display_width_and_alignment() {
+    let suffix = TypeIdSuffix::from_str("01h455vb4pex5vsknk084sn02q").unwrap();
+
+    assert_eq!(format!("{suffix:>30}"), "    01h455vb4pex5vsknk084sn02q");
+    assert_eq!(format!("{suffix:<30}"), "01h455vb4pex5vsknk084sn02q    ");
+    assert_eq!(format!("{suffix:*^30}"), "**01h455vb4pex5vsknk084sn02q**");
+    assert_eq!(format!("{suffix:>#30}"), "    01H455VB4PEX5VSKNK084SN02Q");
+    assert_eq!(format!("{suffix:>10}"), "01h455vb4pex5vsknk084sn02q");
+
+    // Precision never truncates the suffix
+    assert_eq!(format!("{suffix:.8}"), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(format!("{suffix:#.8}"), "01H455VB4PEX5VSKNK084SN02Q");
+    assert_eq!(format!("{suffix:>30.10}"), "    01h455vb4pex5vsknk084sn02q");
+    assert_eq!(format!("{suffix:10}"), "01h455vb4pex5vsknk084sn02q");
+    assert_eq!(format!("{suffix:30}"), "01h455vb4pex5vsknk084sn02q    ");
+}
@@ -0,0 +1,35 @@
+//! Integration tests for the wasm bindings of `TypeIdSuffix`.
+//!
+//! These tests run under `wasm-bindgen-test` on `wasm32` targets and as plain
+//! tests elsewhere, since `generate_v7` and `validate` never touch `JsValue`.
+
+#![cfg(feature = "wasm")]
+
+use typeid_suffix::wasm::{generate_v7, validate};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_validate_generated() {
+    let generated = generate_v7();
+    assert_eq!(generated.len(), 26);
+    assert!(validate(&generated));
+}
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn test_validate_rejects_garbage() {
+    assert!(!validate(""));
+    assert!(!validate("not-a-typeid-suffix"));
+    assert!(!validate("8zzzzzzzzzzzzzzzzzzzzzzzzz"));
+}
+
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen_test]
+fn test_to_uuid() {
+    let uuid = typeid_suffix::wasm::to_uuid("01h455vb4pex5vsknk084sn02q").unwrap();
+    assert_eq!(uuid, "01890a5d-ac96-774b-bcce-b302099a8057");
+    assert!(typeid_suffix::wasm::to_uuid("garbage").is_err());
+}
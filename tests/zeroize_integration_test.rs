@@ -0,0 +1,23 @@
+//! Integration tests for zeroize functionality of `TypeIdSuffix`.
+//!
+//! These tests verify that zeroizing a `TypeIdSuffix` scrubs its encoded bytes.
+
+#![cfg(feature = "zeroize")]
+
+use typeid_suffix::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
+
+#[test]
+fn test_zeroize() {
+    let mut suffix = TypeIdSuffix::default();
+    assert!(suffix.to_array().iter().all(|&b| b != 0));
+
+    suffix.zeroize();
+    assert_eq!(suffix.to_array(), [0u8; 26]);
+}
+
+#[test]
+fn test_zeroizing_wrapper() {
+    let suffix = Zeroizing::new(TypeIdSuffix::default());
+    assert_eq!(suffix.len(), 26);
+}